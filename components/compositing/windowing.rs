@@ -89,6 +89,12 @@ pub enum WindowEvent {
     ToggleWebRenderDebug(WebRenderDebugOption),
     /// Capture current WebRender
     CaptureWebRender,
+    /// Sent when the platform took the audio focus away from a top level browsing context,
+    /// so that its media stops playing.
+    RevokeAudioFocus(TopLevelBrowsingContextId),
+    /// Sent when the platform suspends or resumes the application, to freeze or
+    /// resume the pages of a top level browsing context.
+    SetPageFrozen(TopLevelBrowsingContextId, bool),
 }
 
 impl Debug for WindowEvent {
@@ -116,6 +122,8 @@ impl Debug for WindowEvent {
             WindowEvent::SelectBrowser(..) => write!(f, "SelectBrowser"),
             WindowEvent::ToggleWebRenderDebug(..) => write!(f, "ToggleWebRenderDebug"),
             WindowEvent::CaptureWebRender => write!(f, "CaptureWebRender"),
+            WindowEvent::RevokeAudioFocus(..) => write!(f, "RevokeAudioFocus"),
+            WindowEvent::SetPageFrozen(..) => write!(f, "SetPageFrozen"),
         }
     }
 }
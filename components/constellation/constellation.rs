@@ -372,6 +372,9 @@ pub struct Constellation<Message, LTF, STF> {
 
     /// Navigation requests from script awaiting approval from the embedder.
     pending_approval_navigations: PendingApprovalNavigations,
+
+    /// The pipeline whose media currently holds the audio focus, if any.
+    audio_focus_pipeline: Option<PipelineId>,
 }
 
 /// State needed to construct a constellation.
@@ -705,6 +708,7 @@ where
                     webvr_chan: state.webvr_chan,
                     canvas_chan: CanvasPaintThread::start(),
                     pending_approval_navigations: HashMap::new(),
+                    audio_focus_pipeline: None,
                 };
 
                 constellation.run();
@@ -1187,6 +1191,12 @@ where
                 self.forward_event(destination_pipeline_id, event);
             },
             FromCompositorMsg::SetCursor(cursor) => self.handle_set_cursor_msg(cursor),
+            FromCompositorMsg::RevokeAudioFocus(top_level_browsing_context_id) => {
+                self.handle_revoke_audio_focus_msg(top_level_browsing_context_id);
+            },
+            FromCompositorMsg::SetPageFrozen(top_level_browsing_context_id, frozen) => {
+                self.handle_set_page_frozen_msg(top_level_browsing_context_id, frozen);
+            },
         }
     }
 
@@ -1347,6 +1357,12 @@ where
             FromScriptMsg::LogEntry(thread_name, entry) => {
                 self.handle_log_entry(Some(source_top_ctx_id), thread_name, entry);
             },
+            FromScriptMsg::RequestAudioFocus => {
+                self.handle_request_audio_focus(source_top_ctx_id, source_pipeline_id);
+            },
+            FromScriptMsg::AbandonAudioFocus => {
+                self.handle_abandon_audio_focus(source_top_ctx_id, source_pipeline_id);
+            },
             FromScriptMsg::TouchEventProcessed(result) => self
                 .compositor_proxy
                 .send(ToCompositorMsg::TouchEventProcessed(result)),
@@ -1623,7 +1639,16 @@ where
 
     fn handle_pipeline_exited(&mut self, pipeline_id: PipelineId) {
         debug!("Pipeline {:?} exited.", pipeline_id);
-        self.pipelines.remove(&pipeline_id);
+        let pipeline = self.pipelines.remove(&pipeline_id);
+        if self.audio_focus_pipeline == Some(pipeline_id) {
+            self.audio_focus_pipeline = None;
+            let top_level_browsing_context_id =
+                pipeline.map(|pipeline| pipeline.top_level_browsing_context_id);
+            self.embedder_proxy.send((
+                top_level_browsing_context_id,
+                EmbedderMsg::SetAudioFocus(false),
+            ));
+        }
     }
 
     fn handle_send_error(&mut self, pipeline_id: PipelineId, err: IpcError) {
@@ -2846,6 +2871,91 @@ where
         }
     }
 
+    /// Gives the audio focus to `pipeline_id`, revoking it from the pipeline
+    /// that held it before, and tells the embedder when the focused top level
+    /// browsing context changes.
+    fn handle_request_audio_focus(
+        &mut self,
+        top_level_browsing_context_id: TopLevelBrowsingContextId,
+        pipeline_id: PipelineId,
+    ) {
+        let previous = match self.audio_focus_pipeline {
+            Some(previous) if previous == pipeline_id => return,
+            previous => previous,
+        };
+        self.audio_focus_pipeline = Some(pipeline_id);
+
+        let previous_top_level_browsing_context_id =
+            previous.and_then(|previous| self.revoke_audio_focus(previous));
+        if previous_top_level_browsing_context_id == Some(top_level_browsing_context_id) {
+            return;
+        }
+        if let Some(previous_top_level_browsing_context_id) = previous_top_level_browsing_context_id
+        {
+            self.embedder_proxy.send((
+                Some(previous_top_level_browsing_context_id),
+                EmbedderMsg::SetAudioFocus(false),
+            ));
+        }
+        self.embedder_proxy.send((
+            Some(top_level_browsing_context_id),
+            EmbedderMsg::SetAudioFocus(true),
+        ));
+    }
+
+    fn handle_abandon_audio_focus(
+        &mut self,
+        top_level_browsing_context_id: TopLevelBrowsingContextId,
+        pipeline_id: PipelineId,
+    ) {
+        if self.audio_focus_pipeline != Some(pipeline_id) {
+            return;
+        }
+        self.audio_focus_pipeline = None;
+        self.embedder_proxy.send((
+            Some(top_level_browsing_context_id),
+            EmbedderMsg::SetAudioFocus(false),
+        ));
+    }
+
+    /// The embedder lost the platform audio focus, stop the media of the
+    /// pipeline holding it if it belongs to `top_level_browsing_context_id`.
+    fn handle_revoke_audio_focus_msg(
+        &mut self,
+        top_level_browsing_context_id: TopLevelBrowsingContextId,
+    ) {
+        let pipeline_id = match self.audio_focus_pipeline {
+            Some(pipeline_id) => pipeline_id,
+            None => return,
+        };
+        let holder_top_level_browsing_context_id = self
+            .pipelines
+            .get(&pipeline_id)
+            .map(|pipeline| pipeline.top_level_browsing_context_id);
+        if holder_top_level_browsing_context_id != Some(top_level_browsing_context_id) {
+            return;
+        }
+        self.audio_focus_pipeline = None;
+        self.revoke_audio_focus(pipeline_id);
+    }
+
+    /// Asks the script thread of `pipeline_id` to stop its audible media.
+    /// Returns the top level browsing context of that pipeline, if it still exists.
+    fn revoke_audio_focus(&mut self, pipeline_id: PipelineId) -> Option<TopLevelBrowsingContextId> {
+        let msg = ConstellationControlMsg::RevokeAudioFocus(pipeline_id);
+        let (top_level_browsing_context_id, result) = match self.pipelines.get(&pipeline_id) {
+            None => return None,
+            Some(pipeline) => (
+                pipeline.top_level_browsing_context_id,
+                pipeline.event_loop.send(msg),
+            ),
+        };
+        if let Err(e) = result {
+            self.handle_send_error(pipeline_id, e);
+        }
+        Some(top_level_browsing_context_id)
+    }
+
     fn handle_post_message_msg(
         &mut self,
         browsing_context_id: BrowsingContextId,
@@ -3790,6 +3900,28 @@ where
         }
     }
 
+    /// Freeze or resume every fully active document of a top level browsing context.
+    fn handle_set_page_frozen_msg(
+        &mut self,
+        top_level_browsing_context_id: TopLevelBrowsingContextId,
+        frozen: bool,
+    ) {
+        let pipeline_ids: Vec<PipelineId> = self
+            .fully_active_browsing_contexts_iter(top_level_browsing_context_id)
+            .map(|browsing_context| browsing_context.pipeline_id)
+            .collect();
+        for pipeline_id in pipeline_ids {
+            let msg = ConstellationControlMsg::SetDocumentFrozen(pipeline_id, frozen);
+            let result = match self.pipelines.get(&pipeline_id) {
+                None => continue,
+                Some(pipeline) => pipeline.event_loop.send(msg),
+            };
+            if let Err(e) = result {
+                self.handle_send_error(pipeline_id, e);
+            }
+        }
+    }
+
     /// Update the current activity of a pipeline.
     fn update_activity(&self, pipeline_id: PipelineId) {
         self.set_activity(pipeline_id, self.get_activity(pipeline_id));
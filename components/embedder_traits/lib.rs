@@ -99,6 +99,10 @@ pub enum EmbedderMsg {
     HistoryChanged(Vec<ServoUrl>, usize),
     /// Enter or exit fullscreen
     SetFullscreenState(bool),
+    /// Acquire or abandon the platform audio focus for audible media playback.
+    /// When the platform takes the focus away, the embedder sends
+    /// `WindowEvent::RevokeAudioFocus` back.
+    SetAudioFocus(bool),
     /// The load of a page has begun
     LoadStart,
     /// The load of a page has completed
@@ -115,6 +119,10 @@ pub enum EmbedderMsg {
     ShowIME(InputMethodType),
     /// Request to hide the IME when the editable element is blurred.
     HideIME,
+    /// Query the embedder permission store for a decision about a permission:
+    /// `Some(true)` if it was granted, `Some(false)` if it was denied and `None`
+    /// if the user has not decided yet.
+    QueryPermission(PermissionName, IpcSender<Option<bool>>),
     /// Servo has shut down
     Shutdown,
 }
@@ -136,6 +144,7 @@ impl Debug for EmbedderMsg {
             EmbedderMsg::CloseBrowser => write!(f, "CloseBrowser"),
             EmbedderMsg::HistoryChanged(..) => write!(f, "HistoryChanged"),
             EmbedderMsg::SetFullscreenState(..) => write!(f, "SetFullscreenState"),
+            EmbedderMsg::SetAudioFocus(..) => write!(f, "SetAudioFocus"),
             EmbedderMsg::LoadStart => write!(f, "LoadStart"),
             EmbedderMsg::LoadComplete => write!(f, "LoadComplete"),
             EmbedderMsg::Panic(..) => write!(f, "Panic"),
@@ -143,6 +152,7 @@ impl Debug for EmbedderMsg {
             EmbedderMsg::SelectFiles(..) => write!(f, "SelectFiles"),
             EmbedderMsg::ShowIME(..) => write!(f, "ShowIME"),
             EmbedderMsg::HideIME => write!(f, "HideIME"),
+            EmbedderMsg::QueryPermission(..) => write!(f, "QueryPermission"),
             EmbedderMsg::Shutdown => write!(f, "Shutdown"),
             EmbedderMsg::AllowOpeningBrowser(..) => write!(f, "AllowOpeningBrowser"),
             EmbedderMsg::BrowserCreated(..) => write!(f, "BrowserCreated"),
@@ -150,6 +160,16 @@ impl Debug for EmbedderMsg {
     }
 }
 
+/// A permission whose state is kept in the embedder permission store.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum PermissionName {
+    Camera,
+    Microphone,
+    PersistentStorage,
+    /// Whether media elements may start playing without user interaction.
+    Autoplay,
+}
+
 /// Filter for file selection;
 /// the `String` content is expected to be extension (e.g, "doc", without the prefixing ".")
 #[derive(Clone, Debug, Deserialize, Serialize)]
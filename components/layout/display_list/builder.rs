@@ -49,6 +49,7 @@ use std::f32;
 use std::mem;
 use std::sync::Arc;
 use style::computed_values::border_style::T as BorderStyle;
+use style::computed_values::object_fit::T as ObjectFit;
 use style::computed_values::overflow_x::T as StyleOverflow;
 use style::computed_values::pointer_events::T as PointerEvents;
 use style::computed_values::position::T as StylePosition;
@@ -823,6 +824,103 @@ fn build_border_radius_for_inner_rect(
     border::inner_radii(radii, border_widths)
 }
 
+/// Computes the rectangle a replaced element's content of the given intrinsic
+/// size is drawn into, honoring `object-fit` and `object-position`.
+///
+/// <https://drafts.csswg.org/css-images-3/#the-object-fit>
+fn object_fit_rect(
+    content_box: Rect<Au>,
+    intrinsic_size: Size2D<Au>,
+    style: &ComputedValues,
+) -> Rect<Au> {
+    if intrinsic_size.width == Au(0) || intrinsic_size.height == Au(0) {
+        return content_box;
+    }
+
+    let contain_or_cover = |cover: bool| {
+        let width_ratio = content_box.size.width.to_f32_px() / intrinsic_size.width.to_f32_px();
+        let height_ratio = content_box.size.height.to_f32_px() / intrinsic_size.height.to_f32_px();
+        let ratio = if cover {
+            width_ratio.max(height_ratio)
+        } else {
+            width_ratio.min(height_ratio)
+        };
+        Size2D::new(
+            intrinsic_size.width.scale_by(ratio),
+            intrinsic_size.height.scale_by(ratio),
+        )
+    };
+
+    let position = style.get_position();
+    let concrete_size = match position.object_fit {
+        ObjectFit::Fill => return content_box,
+        ObjectFit::Contain => contain_or_cover(false),
+        ObjectFit::Cover => contain_or_cover(true),
+        ObjectFit::None => intrinsic_size,
+        ObjectFit::ScaleDown => {
+            let contained = contain_or_cover(false);
+            if contained.width < intrinsic_size.width {
+                contained
+            } else {
+                intrinsic_size
+            }
+        },
+    };
+
+    let free_space = content_box.size - concrete_size;
+    let origin = Point2D::new(
+        content_box.origin.x +
+            position
+                .object_position
+                .horizontal
+                .to_used_value(free_space.width),
+        content_box.origin.y +
+            position
+                .object_position
+                .vertical
+                .to_used_value(free_space.height),
+    );
+    Rect::new(origin, concrete_size)
+}
+
+/// Adds an image item drawing replaced content of the given intrinsic size
+/// into the content box that `base` covers, as `object-fit` and
+/// `object-position` mandate.
+///
+/// When the drawn rectangle differs from the content box, the image is clipped
+/// to the content box and a transparent rectangle keeps the whole content box
+/// hit-testable.
+fn add_object_fit_image_item(
+    state: &mut DisplayListBuildState,
+    base: BaseDisplayItem,
+    content_box: Rect<Au>,
+    intrinsic_size: Size2D<Au>,
+    style: &ComputedValues,
+    mut item: webrender_api::ImageDisplayItem,
+) {
+    let object_rect = object_fit_rect(content_box, intrinsic_size, style);
+    item.stretch_size = object_rect.size.to_layout();
+    if object_rect == content_box {
+        return state.add_image_item(base, item);
+    }
+
+    let mut image_base = base.clone();
+    image_base.bounds = object_rect.to_layout();
+    image_base.clip_rect = base
+        .clip_rect
+        .intersection(&content_box.to_layout())
+        .unwrap_or(LayoutRect::zero());
+    image_base.metadata.pointing = None;
+
+    state.add_display_item(DisplayItem::Rectangle(CommonDisplayItem::new(
+        base,
+        webrender_api::RectangleDisplayItem {
+            color: ColorF::TRANSPARENT,
+        },
+    )));
+    state.add_image_item(image_base, item);
+}
+
 impl FragmentDisplayListBuilding for Fragment {
     fn collect_stacking_contexts_for_blocklike_fragment(
         &mut self,
@@ -2013,8 +2111,15 @@ impl FragmentDisplayListBuilding for Fragment {
                 if let Some(ref image) = image_fragment.image {
                     if let Some(id) = image.id {
                         let base = create_base_display_item(state);
-                        state.add_image_item(
+                        add_object_fit_image_item(
+                            state,
                             base,
+                            stacking_relative_content_box,
+                            Size2D::new(
+                                Au::from_px(image.width as i32),
+                                Au::from_px(image.height as i32),
+                            ),
+                            &self.style,
                             webrender_api::ImageDisplayItem {
                                 image_key: id,
                                 stretch_size: stacking_relative_content_box.size.to_layout(),
@@ -2032,13 +2137,17 @@ impl FragmentDisplayListBuilding for Fragment {
                 }
             },
             SpecificFragmentInfo::Media(ref fragment_info) => {
-                if let Some((ref image_key, _, _)) = fragment_info.current_frame {
+                if let Some((ref image_key, width, height)) = fragment_info.current_frame {
                     let base = create_base_display_item(state);
-                    state.add_image_item(
+                    add_object_fit_image_item(
+                        state,
                         base,
+                        stacking_relative_content_box,
+                        Size2D::new(Au::from_px(width), Au::from_px(height)),
+                        &self.style,
                         webrender_api::ImageDisplayItem {
                             image_key: *image_key,
-                            stretch_size: stacking_relative_border_box.size.to_layout(),
+                            stretch_size: stacking_relative_content_box.size.to_layout(),
                             tile_spacing: LayoutSize::zero(),
                             image_rendering: ImageRendering::Auto,
                             alpha_type: webrender_api::AlphaType::PremultipliedAlpha,
@@ -2067,16 +2176,24 @@ impl FragmentDisplayListBuilding for Fragment {
                 };
 
                 let base = create_base_display_item(state);
-                let display_item = webrender_api::ImageDisplayItem {
-                    image_key,
-                    stretch_size: stacking_relative_content_box.size.to_layout(),
-                    tile_spacing: LayoutSize::zero(),
-                    image_rendering: ImageRendering::Auto,
-                    alpha_type: webrender_api::AlphaType::PremultipliedAlpha,
-                    color: webrender_api::ColorF::WHITE,
-                };
-
-                state.add_image_item(base, display_item);
+                add_object_fit_image_item(
+                    state,
+                    base,
+                    stacking_relative_content_box,
+                    Size2D::new(
+                        canvas_fragment_info.dom_width,
+                        canvas_fragment_info.dom_height,
+                    ),
+                    &self.style,
+                    webrender_api::ImageDisplayItem {
+                        image_key,
+                        stretch_size: stacking_relative_content_box.size.to_layout(),
+                        tile_spacing: LayoutSize::zero(),
+                        image_rendering: ImageRendering::Auto,
+                        alpha_type: webrender_api::AlphaType::PremultipliedAlpha,
+                        color: webrender_api::ColorF::WHITE,
+                    },
+                );
             },
             SpecificFragmentInfo::UnscannedText(_) => {
                 panic!("Shouldn't see unscanned fragments here.")
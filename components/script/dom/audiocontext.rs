@@ -12,6 +12,7 @@ use crate::dom::bindings::codegen::Bindings::AudioContextBinding::{
 };
 use crate::dom::bindings::codegen::Bindings::BaseAudioContextBinding::AudioContextState;
 use crate::dom::bindings::codegen::Bindings::BaseAudioContextBinding::BaseAudioContextBinding::BaseAudioContextMethods;
+use crate::dom::bindings::codegen::UnionTypes::AudioContextLatencyCategoryOrDouble;
 use crate::dom::bindings::error::{Error, Fallible};
 use crate::dom::bindings::inheritance::Castable;
 use crate::dom::bindings::num::Finite;
@@ -25,6 +26,15 @@ use dom_struct::dom_struct;
 use servo_media::audio::context::{LatencyCategory, ProcessingState, RealTimeAudioContextOptions};
 use std::rc::Rc;
 
+/// https://webaudio.github.io/web-audio-api/#render-quantum-size
+const RENDER_QUANTUM_SIZE: u32 = 128;
+
+/// The number of frames the backend is asked to buffer for each latency
+/// category. Latencies given in seconds are clamped to this range.
+const INTERACTIVE_BUFFER_SIZE: u32 = RENDER_QUANTUM_SIZE;
+const BALANCED_BUFFER_SIZE: u32 = 4 * RENDER_QUANTUM_SIZE;
+const PLAYBACK_BUFFER_SIZE: u32 = 8 * RENDER_QUANTUM_SIZE;
+
 #[dom_struct]
 pub struct AudioContext {
     context: BaseAudioContext,
@@ -44,7 +54,8 @@ impl AudioContext {
             BaseAudioContext::new_inherited(BaseAudioContextOptions::AudioContext(options.into()));
 
         // Step 4.1.
-        let latency_hint = options.latencyHint;
+        let buffer_size = buffer_size(options, *context.SampleRate());
+        let latency_hint = latency_category(buffer_size);
 
         // Step 4.2. The sample rate is set during the creation of the BaseAudioContext.
         // servo-media takes care of setting the default sample rate of the output device
@@ -53,10 +64,12 @@ impl AudioContext {
         // Steps 5 and 6 of the construction algorithm will happen in `resume`,
         // after reflecting dom object.
 
+        let base_latency = buffer_size as f64 / *context.SampleRate() as f64;
+
         AudioContext {
             context,
             latency_hint,
-            base_latency: 0.,   // TODO
+            base_latency,
             output_latency: 0., // TODO
         }
     }
@@ -229,6 +242,44 @@ impl AudioContextMethods for AudioContext {
     }
 }
 
+/// https://webaudio.github.io/web-audio-api/#dom-audiocontextoptions-latencyhint
+///
+/// Returns the number of frames to buffer for the given latency hint. A
+/// latency given in seconds is rounded up to a whole number of render quanta
+/// and clamped between the interactive and playback buffer sizes.
+fn buffer_size(options: &AudioContextOptions, sample_rate: f32) -> u32 {
+    match options.latencyHint {
+        Some(AudioContextLatencyCategoryOrDouble::AudioContextLatencyCategory(category)) => {
+            match category {
+                AudioContextLatencyCategory::Interactive => INTERACTIVE_BUFFER_SIZE,
+                AudioContextLatencyCategory::Balanced => BALANCED_BUFFER_SIZE,
+                AudioContextLatencyCategory::Playback => PLAYBACK_BUFFER_SIZE,
+            }
+        },
+        Some(AudioContextLatencyCategoryOrDouble::Double(seconds)) => {
+            let frames = (*seconds * sample_rate as f64)
+                .max(INTERACTIVE_BUFFER_SIZE as f64)
+                .min(PLAYBACK_BUFFER_SIZE as f64) as u32;
+            let quanta = (frames + RENDER_QUANTUM_SIZE - 1) / RENDER_QUANTUM_SIZE;
+            quanta * RENDER_QUANTUM_SIZE
+        },
+        None => INTERACTIVE_BUFFER_SIZE,
+    }
+}
+
+/// The latency category servo-media is configured with for a buffer size.
+/// The backend only knows about categories, so a buffer size in between two
+/// categories selects the larger one.
+fn latency_category(buffer_size: u32) -> AudioContextLatencyCategory {
+    if buffer_size <= INTERACTIVE_BUFFER_SIZE {
+        AudioContextLatencyCategory::Interactive
+    } else if buffer_size <= BALANCED_BUFFER_SIZE {
+        AudioContextLatencyCategory::Balanced
+    } else {
+        AudioContextLatencyCategory::Playback
+    }
+}
+
 impl From<AudioContextLatencyCategory> for LatencyCategory {
     fn from(category: AudioContextLatencyCategory) -> Self {
         match category {
@@ -241,9 +292,10 @@ impl From<AudioContextLatencyCategory> for LatencyCategory {
 
 impl<'a> From<&'a AudioContextOptions> for RealTimeAudioContextOptions {
     fn from(options: &AudioContextOptions) -> Self {
+        let sample_rate = *options.sampleRate.unwrap_or(Finite::wrap(44100.));
         Self {
-            sample_rate: *options.sampleRate.unwrap_or(Finite::wrap(44100.)),
-            latency_hint: options.latencyHint.into(),
+            sample_rate,
+            latency_hint: latency_category(buffer_size(options, sample_rate)).into(),
         }
     }
 }
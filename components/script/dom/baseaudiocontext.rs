@@ -22,6 +22,7 @@ use crate::dom::bindings::codegen::Bindings::BaseAudioContextBinding::DecodeErro
 use crate::dom::bindings::codegen::Bindings::BaseAudioContextBinding::DecodeSuccessCallback;
 use crate::dom::bindings::codegen::Bindings::BiquadFilterNodeBinding::BiquadFilterOptions;
 use crate::dom::bindings::codegen::Bindings::ChannelMergerNodeBinding::ChannelMergerOptions;
+use crate::dom::bindings::codegen::Bindings::ChannelSplitterNodeBinding::ChannelSplitterOptions;
 use crate::dom::bindings::codegen::Bindings::GainNodeBinding::GainOptions;
 use crate::dom::bindings::codegen::Bindings::OscillatorNodeBinding::OscillatorOptions;
 use crate::dom::bindings::codegen::Bindings::PannerNodeBinding::PannerOptions;
@@ -33,6 +34,7 @@ use crate::dom::bindings::reflector::DomObject;
 use crate::dom::bindings::root::{DomRoot, MutNullableDom};
 use crate::dom::biquadfilternode::BiquadFilterNode;
 use crate::dom::channelmergernode::ChannelMergerNode;
+use crate::dom::channelsplitternode::ChannelSplitterNode;
 use crate::dom::domexception::{DOMErrorName, DOMException};
 use crate::dom::eventtarget::EventTarget;
 use crate::dom::gainnode::GainNode;
@@ -94,15 +96,17 @@ pub struct BaseAudioContext {
     /// been "closed()".
     state: Cell<AudioContextState>,
     channel_count: u32,
+    /// Whether this is the base of an OfflineAudioContext.
+    offline: bool,
 }
 
 impl BaseAudioContext {
     #[allow(unrooted_must_root)]
     pub fn new_inherited(options: BaseAudioContextOptions) -> BaseAudioContext {
-        let (sample_rate, channel_count) = match options {
-            BaseAudioContextOptions::AudioContext(ref opt) => (opt.sample_rate, 2),
+        let (sample_rate, channel_count, offline) = match options {
+            BaseAudioContextOptions::AudioContext(ref opt) => (opt.sample_rate, 2, false),
             BaseAudioContextOptions::OfflineAudioContext(ref opt) => {
-                (opt.sample_rate, opt.channels)
+                (opt.sample_rate, opt.channels, true)
             },
         };
 
@@ -119,6 +123,7 @@ impl BaseAudioContext {
             sample_rate,
             state: Cell::new(AudioContextState::Suspended),
             channel_count: channel_count.into(),
+            offline,
         };
 
         context
@@ -126,7 +131,7 @@ impl BaseAudioContext {
 
     /// Tells whether this is an OfflineAudioContext or not.
     pub fn is_offline(&self) -> bool {
-        false
+        self.offline
     }
 
     pub fn audio_context_impl(&self) -> &AudioContext<Backend> {
@@ -143,7 +148,11 @@ impl BaseAudioContext {
 
     // https://webaudio.github.io/web-audio-api/#allowed-to-start
     pub fn is_allowed_to_start(&self) -> bool {
-        self.state.get() == AudioContextState::Suspended
+        self.state.get() == AudioContextState::Suspended &&
+            (self.is_offline() ||
+                self.global()
+                    .as_window()
+                    .is_allowed_by_user_activation(/* transient */ false))
     }
 
     fn push_pending_resume_promise(&self, promise: &Rc<Promise>) {
@@ -360,6 +369,13 @@ impl BaseAudioContextMethods for BaseAudioContext {
         ChannelMergerNode::new(&self.global().as_window(), &self, &opts)
     }
 
+    /// https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-createchannelsplitter
+    fn CreateChannelSplitter(&self, count: u32) -> Fallible<DomRoot<ChannelSplitterNode>> {
+        let mut opts = ChannelSplitterOptions::empty();
+        opts.numberOfOutputs = count;
+        ChannelSplitterNode::new(&self.global().as_window(), &self, &opts)
+    }
+
     /// https://webaudio.github.io/web-audio-api/#dom-baseaudiocontext-createbuffer
     fn CreateBuffer(
         &self,
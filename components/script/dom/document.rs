@@ -66,6 +66,7 @@ use crate::dom::htmlheadelement::HTMLHeadElement;
 use crate::dom::htmlhtmlelement::HTMLHtmlElement;
 use crate::dom::htmliframeelement::HTMLIFrameElement;
 use crate::dom::htmlimageelement::HTMLImageElement;
+use crate::dom::htmlmediaelement::HTMLMediaElement;
 use crate::dom::htmlmetaelement::HTMLMetaElement;
 use crate::dom::htmlscriptelement::{HTMLScriptElement, ScriptResult};
 use crate::dom::htmltitleelement::HTMLTitleElement;
@@ -79,6 +80,7 @@ use crate::dom::node::{LayoutNodeHelpers, Node, NodeDamage, NodeFlags};
 use crate::dom::nodeiterator::NodeIterator;
 use crate::dom::nodelist::NodeList;
 use crate::dom::pagetransitionevent::PageTransitionEvent;
+use crate::dom::permissions::query_embedder_permission_store;
 use crate::dom::popstateevent::PopStateEvent;
 use crate::dom::processinginstruction::ProcessingInstruction;
 use crate::dom::progressevent::ProgressEvent;
@@ -106,7 +108,7 @@ use crate::timers::OneshotTimerCallback;
 use cookie::Cookie;
 use devtools_traits::ScriptToDevtoolsControlMsg;
 use dom_struct::dom_struct;
-use embedder_traits::EmbedderMsg;
+use embedder_traits::{EmbedderMsg, PermissionName as EmbedderPermissionName};
 use encoding_rs::{Encoding, UTF_8};
 use euclid::Point2D;
 use html5ever::{LocalName, Namespace, QualName};
@@ -403,8 +405,16 @@ pub struct Document {
     throw_on_dynamic_markup_insertion_counter: Cell<u64>,
     /// https://html.spec.whatwg.org/multipage/#page-showing
     page_showing: Cell<bool>,
+    /// https://wicg.github.io/page-lifecycle/#frozen
+    frozen: Cell<bool>,
+    /// Whether the embedder asked for this document to be frozen, e.g. because
+    /// it was suspended by the platform.
+    frozen_by_embedder: Cell<bool>,
     /// Whether the document is salvageable.
     salvageable: Cell<bool>,
+    /// The embedder's answer to the autoplay permission query, asked at most
+    /// once per document.
+    autoplay_permission: Cell<Option<Option<bool>>>,
     /// Whether the unload event has already been fired.
     fired_unload: Cell<bool>,
     /// List of responsive images
@@ -533,6 +543,12 @@ impl Document {
                 self.window()
                     .reflow(ReflowGoal::Full, ReflowReason::CachedPageNeededReflow);
                 self.window().resume();
+                if self.frozen_by_embedder.get() {
+                    // The embedder still holds the page frozen.
+                    self.window.upcast::<GlobalScope>().suspend();
+                } else {
+                    self.resume_frozen_page();
+                }
                 // html.spec.whatwg.org/multipage/#history-traversal
                 // Step 4.6
                 if self.ready_state.get() == DocumentReadyState::Complete {
@@ -571,11 +587,72 @@ impl Document {
                         .unwrap();
                 }
             } else {
+                self.freeze_page();
                 self.window().suspend();
             }
         }
     }
 
+    /// Freezes or resumes this document on behalf of the embedder, e.g. when
+    /// it is suspended by the platform. Unlike a navigation, this only stops
+    /// the timers and the media elements, and the window stays the active one.
+    /// Documents that are not fully active are already frozen by
+    /// `set_activity`, which keeps them frozen once they become fully active
+    /// again as long as the embedder has not resumed them.
+    pub fn set_frozen(&self, frozen: bool) {
+        if frozen == self.frozen_by_embedder.get() {
+            return;
+        }
+        self.frozen_by_embedder.set(frozen);
+        if !self.is_fully_active() {
+            return;
+        }
+        if frozen {
+            self.freeze_page();
+            self.window.upcast::<GlobalScope>().suspend();
+        } else {
+            self.window.upcast::<GlobalScope>().resume();
+            self.resume_frozen_page();
+        }
+    }
+
+    /// https://wicg.github.io/page-lifecycle/#frozen
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.get()
+    }
+
+    /// https://wicg.github.io/page-lifecycle/#freeze-steps
+    fn freeze_page(&self) {
+        if self.frozen.get() {
+            return;
+        }
+        self.upcast::<EventTarget>().fire_event(atom!("freeze"));
+        self.frozen.set(true);
+        for media in self
+            .upcast::<Node>()
+            .traverse_preorder()
+            .filter_map(DomRoot::downcast::<HTMLMediaElement>)
+        {
+            media.freeze();
+        }
+    }
+
+    /// https://wicg.github.io/page-lifecycle/#resume-steps
+    fn resume_frozen_page(&self) {
+        if !self.frozen.get() {
+            return;
+        }
+        self.frozen.set(false);
+        for media in self
+            .upcast::<Node>()
+            .traverse_preorder()
+            .filter_map(DomRoot::downcast::<HTMLMediaElement>)
+        {
+            media.thaw();
+        }
+        self.upcast::<EventTarget>().fire_event(atom!("resume"));
+    }
+
     pub fn origin(&self) -> &MutableOrigin {
         &self.origin
     }
@@ -1051,6 +1128,12 @@ impl Document {
 
         // https://w3c.github.io/uievents/#trusted-events
         event.set_trusted(true);
+
+        // https://html.spec.whatwg.org/multipage/#activation-triggering-input-event
+        if let MouseEventType::MouseDown = mouse_event_type {
+            self.window.notify_user_activation();
+        }
+
         // https://html.spec.whatwg.org/multipage/#run-authentic-click-activation-steps
         let activatable = el.as_maybe_activatable();
         match mouse_event_type {
@@ -1371,6 +1454,12 @@ impl Document {
             false,
         );
         let event = event.upcast::<Event>();
+
+        // https://html.spec.whatwg.org/multipage/#activation-triggering-input-event
+        if let TouchEventType::Up = event_type {
+            self.window.notify_user_activation();
+        }
+
         let result = event.fire(&target);
 
         window.reflow(ReflowGoal::Full, ReflowReason::MouseEvent);
@@ -1409,6 +1498,12 @@ impl Document {
             keyboard_event.key.legacy_keycode(),
         );
         let event = keyevent.upcast::<Event>();
+
+        // https://html.spec.whatwg.org/multipage/#activation-triggering-input-event
+        if keyboard_event.state == KeyState::Down && keyboard_event.key != Key::Escape {
+            self.window.notify_user_activation();
+        }
+
         event.fire(target);
         let mut cancel_state = event.get_cancel_state();
 
@@ -2738,7 +2833,10 @@ impl Document {
             canceller: canceller,
             throw_on_dynamic_markup_insertion_counter: Cell::new(0),
             page_showing: Cell::new(false),
+            frozen: Cell::new(false),
+            frozen_by_embedder: Cell::new(false),
             salvageable: Cell::new(true),
+            autoplay_permission: Cell::new(None),
             fired_unload: Cell::new(false),
             responsive_images: Default::default(),
             redirect_count: Cell::new(0),
@@ -2982,6 +3080,29 @@ impl Document {
         self.salvageable.get()
     }
 
+    /// Whether media elements in this document may start playing without a
+    /// user gesture. The embedder permission store is only consulted when
+    /// autoplay is enabled, and its answer is kept for the document lifetime.
+    pub fn is_autoplay_allowed(&self) -> bool {
+        if !PREFS
+            .get("media.autoplay.enabled")
+            .as_boolean()
+            .unwrap_or(true)
+        {
+            return false;
+        }
+        let stored = match self.autoplay_permission.get() {
+            Some(stored) => stored,
+            None => {
+                let stored =
+                    query_embedder_permission_store(&self.window, EmbedderPermissionName::Autoplay);
+                self.autoplay_permission.set(Some(stored));
+                stored
+            },
+        };
+        stored.unwrap_or(true)
+    }
+
     pub fn stylesheet_at(&self, index: usize) -> Option<DomRoot<CSSStyleSheet>> {
         let stylesheets = self.stylesheets.borrow();
 
@@ -3165,13 +3286,20 @@ impl Document {
             error = true;
         }
         // TODO fullscreen is supported
-        // TODO This algorithm is allowed to request fullscreen.
+        // This algorithm is allowed to request fullscreen.
+        if !self
+            .window
+            .is_allowed_by_user_activation(/* transient */ true)
+        {
+            error = true;
+        }
 
         // Step 5 Parallel start
 
         let window = self.window();
         // Step 6
         if !error {
+            self.window.consume_user_activation();
             let event = EmbedderMsg::SetFullscreenState(true);
             self.send_to_embedder(event);
         }
@@ -4543,6 +4671,12 @@ impl DocumentMethods for Document {
         SetOnfullscreenchange
     );
 
+    // https://wicg.github.io/page-lifecycle/#dom-document-onfreeze
+    event_handler!(freeze, GetOnfreeze, SetOnfreeze);
+
+    // https://wicg.github.io/page-lifecycle/#dom-document-onresume
+    event_handler!(resume, GetOnresume, SetOnresume);
+
     // https://fullscreen.spec.whatwg.org/#dom-document-fullscreenenabled
     fn FullscreenEnabled(&self) -> bool {
         self.get_allow_fullscreen()
@@ -36,6 +36,7 @@ pub enum DOMErrorName {
     InvalidNodeTypeError = DOMExceptionConstants::INVALID_NODE_TYPE_ERR,
     DataCloneError = DOMExceptionConstants::DATA_CLONE_ERR,
     NotReadableError = DOMExceptionConstants::NOT_READABLE_ERR,
+    NotAllowedError = DOMExceptionConstants::NOT_ALLOWED_ERR,
 }
 
 #[dom_struct]
@@ -103,6 +104,9 @@ impl DOMExceptionMethods for DOMException {
             },
             DOMErrorName::DataCloneError => "The object can not be cloned.",
             DOMErrorName::NotReadableError => "The I/O read operation failed.",
+            DOMErrorName::NotAllowedError => {
+                "The request is not allowed by the user agent or the platform in the current context."
+            },
         };
 
         DOMString::from(message)
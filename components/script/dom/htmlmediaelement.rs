@@ -24,12 +24,14 @@ use crate::dom::bindings::root::{DomRoot, LayoutDom, MutNullableDom};
 use crate::dom::bindings::str::{DOMString, USVString};
 use crate::dom::blob::Blob;
 use crate::dom::document::Document;
+use crate::dom::domtokenlist::DOMTokenList;
 use crate::dom::element::{AttributeMutation, Element};
 use crate::dom::eventtarget::EventTarget;
 use crate::dom::globalscope::GlobalScope;
 use crate::dom::htmlelement::HTMLElement;
 use crate::dom::htmlsourceelement::HTMLSourceElement;
 use crate::dom::htmlvideoelement::HTMLVideoElement;
+use crate::dom::mediacontroller::MediaController;
 use crate::dom::mediaerror::MediaError;
 use crate::dom::node::{document_from_node, window_from_node, Node, NodeDamage, UnbindContext};
 use crate::dom::performanceresourcetiming::InitiatorType;
@@ -55,6 +57,8 @@ use net_traits::request::{CredentialsMode, Destination, RequestInit};
 use net_traits::{CoreResourceMsg, FetchChannels, FetchMetadata, FetchResponseListener, Metadata};
 use net_traits::{NetworkError, ResourceFetchTiming, ResourceTimingType};
 use script_layout_interface::HTMLMediaData;
+use script_traits::ScriptMsg;
+use servo_config::prefs::PREFS;
 use servo_media::player::frame::{Frame, FrameRenderer};
 use servo_media::player::{PlaybackState, Player, PlayerEvent, StreamType};
 use servo_media::Error as ServoMediaError;
@@ -66,6 +70,7 @@ use std::f64;
 use std::mem;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
+use style::attr::AttrValue;
 use time::{self, Duration, Timespec};
 use webrender_api::{ImageData, ImageDescriptor, ImageFormat, ImageKey, RenderApi};
 use webrender_api::{RenderApiSender, Transaction};
@@ -86,6 +91,20 @@ impl MediaFrameRenderer {
             very_old_frame: None,
         }
     }
+
+    /// Releases every frame held for painting.
+    fn clear(&mut self) {
+        let mut txn = Transaction::new();
+        let current_frame = self.current_frame.take().map(|(image_key, _, _)| image_key);
+        for image_key in current_frame
+            .into_iter()
+            .chain(self.old_frame.take())
+            .chain(self.very_old_frame.take())
+        {
+            txn.delete_image(image_key);
+        }
+        self.api.update_resources(txn.resource_updates);
+    }
 }
 
 impl FrameRenderer for MediaFrameRenderer {
@@ -195,6 +214,9 @@ pub struct HTMLMediaElement {
     volume: Cell<f64>,
     /// https://html.spec.whatwg.org/multipage/#dom-media-seeking
     seeking: Cell<bool>,
+    /// Whether the media engine is seeking to render the current frame again
+    /// after a `thaw`, which must not run the seek completion steps.
+    redrawing_frame: Cell<bool>,
     /// URL of the media resource, if any.
     resource_url: DomRefCell<Option<ServoUrl>>,
     /// https://html.spec.whatwg.org/multipage/#dom-media-played
@@ -204,6 +226,10 @@ pub struct HTMLMediaElement {
     text_tracks_list: MutNullableDom<TextTrackList>,
     /// Expected content length of the media asset being fetched or played.
     content_length: Cell<Option<u64>>,
+    /// https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#dom-media-controller
+    controller: MutNullableDom<MediaController>,
+    /// https://wicg.github.io/controls-list/#dom-htmlmediaelement-controlslist
+    controls_list: MutNullableDom<DOMTokenList>,
 }
 
 /// <https://html.spec.whatwg.org/multipage/#dom-media-networkstate>
@@ -257,10 +283,13 @@ impl HTMLMediaElement {
             default_playback_start_position: Cell::new(0.),
             volume: Cell::new(1.0),
             seeking: Cell::new(false),
+            redrawing_frame: Cell::new(false),
             resource_url: DomRefCell::new(None),
             played: Rc::new(DomRefCell::new(TimeRangesContainer::new())),
             text_tracks_list: Default::default(),
             content_length: Cell::new(None),
+            controller: Default::default(),
+            controls_list: Default::default(),
         }
     }
 
@@ -278,6 +307,11 @@ impl HTMLMediaElement {
     }
 
     fn play_media(&self) {
+        // Playback of a frozen document only starts again on `thaw`.
+        if document_from_node(self).is_frozen() {
+            return;
+        }
+        self.request_audio_focus();
         if let Err(e) = self.player.set_rate(self.playbackRate.get()) {
             warn!("Could not set the playback rate {:?}", e);
         }
@@ -286,6 +320,149 @@ impl HTMLMediaElement {
         }
     }
 
+    /// <https://html.spec.whatwg.org/multipage/#allowed-to-play>
+    ///
+    /// Inaudible media may always play, audible media only once the page has
+    /// been activated by the user.
+    fn is_allowed_to_play(&self) -> bool {
+        self.volume.get() == 0. ||
+            window_from_node(self).is_allowed_by_user_activation(/* transient */ false)
+    }
+
+    /// https://html.spec.whatwg.org/multipage/#eligible-for-autoplay
+    ///
+    /// The `media.autoplay.enabled` pref turns autoplay off altogether;
+    /// otherwise a decision kept in the embedder permission store applies.
+    fn is_eligible_for_autoplay(&self) -> bool {
+        document_from_node(self).is_autoplay_allowed()
+    }
+
+    /// Stops the media backend when the element's document is frozen, e.g.
+    /// when it is kept in the session history or the embedder is suspended.
+    /// Decoding and audio output stop and the frames held for painting are
+    /// released. The element's paused state is left untouched so that `thaw`
+    /// can pick playback up at the position it was frozen at.
+    pub fn freeze(&self) {
+        if !self.Paused() {
+            self.abandon_audio_focus();
+            if let Err(e) = self.player.pause() {
+                warn!("Could not pause player {:?}", e);
+            }
+        }
+        self.frame_renderer.lock().unwrap().clear();
+        self.upcast::<Node>().dirty(NodeDamage::OtherNodeDamage);
+    }
+
+    /// Resumes the media backend stopped by `freeze` once the element's
+    /// document is resumed.
+    pub fn thaw(&self) {
+        let ready_state = self.ready_state.get();
+        if !self.Paused() {
+            // Otherwise playback starts once enough data is available, when
+            // the ready state changes.
+            if ready_state >= ReadyState::HaveFutureData {
+                self.notify_about_playing();
+            }
+            return;
+        }
+        // Render the frame at the current position again, as the frames
+        // were released on freeze.
+        if ready_state >= ReadyState::HaveCurrentData {
+            self.redrawing_frame.set(true);
+            if let Err(e) = self.player.seek(self.playback_position.get()) {
+                self.redrawing_frame.set(false);
+                warn!("Could not seek player {:?}", e);
+            }
+        }
+    }
+
+    /// Takes the audio focus for this element, pausing the element that held
+    /// it before, if any.
+    ///
+    /// This is a no-op unless the `media.audio_focus.enabled` pref is set, and
+    /// only audible elements compete for the audio focus.
+    fn request_audio_focus(&self) {
+        if !PREFS
+            .get("media.audio_focus.enabled")
+            .as_boolean()
+            .unwrap_or(false)
+        {
+            return;
+        }
+
+        if self.volume.get() == 0. {
+            return;
+        }
+
+        let previous_holder = ScriptThread::audio_focus_holder();
+        if let Some(ref previous_holder) = previous_holder {
+            // Elements slaved to the same controller play as a group and
+            // share the audio focus.
+            if *previous_holder == DomRoot::from_ref(self) ||
+                self.shares_controller_with(previous_holder)
+            {
+                return;
+            }
+        }
+
+        ScriptThread::set_audio_focus_holder(Some(self));
+        if let Some(previous_holder) = previous_holder {
+            previous_holder.lose_audio_focus();
+        }
+
+        // The constellation revokes the focus from media in other script
+        // threads and tells the embedder about it.
+        window_from_node(self).send_to_constellation(ScriptMsg::RequestAudioFocus);
+    }
+
+    /// Gives up the audio focus if this element is holding it. If another
+    /// element of the same group is still playing, the focus passes to it.
+    fn abandon_audio_focus(&self) {
+        match ScriptThread::audio_focus_holder() {
+            Some(ref holder) if *holder == DomRoot::from_ref(self) => {},
+            _ => return,
+        }
+
+        if let Some(controller) = self.controller.get() {
+            let window = window_from_node(self);
+            let next_holder = controller.slaves().into_iter().find(|slave| {
+                *slave != DomRoot::from_ref(self) &&
+                    !slave.Paused() &&
+                    window_from_node(&**slave) == window
+            });
+            if let Some(next_holder) = next_holder {
+                return ScriptThread::set_audio_focus_holder(Some(&next_holder));
+            }
+        }
+
+        ScriptThread::set_audio_focus_holder(None);
+        window_from_node(self).send_to_constellation(ScriptMsg::AbandonAudioFocus);
+    }
+
+    /// Lets the media controller this element is slaved to, if any, know
+    /// that the element's paused, ready or seeking state changed.
+    fn update_controller_clock(&self) {
+        if let Some(controller) = self.controller.get() {
+            controller.update_clock();
+        }
+    }
+
+    fn shares_controller_with(&self, other: &HTMLMediaElement) -> bool {
+        match (self.controller.get(), other.controller.get()) {
+            (Some(ref controller), Some(ref other_controller)) => controller == other_controller,
+            _ => false,
+        }
+    }
+
+    /// Stops playback after the audio focus was taken away from this element.
+    /// The whole group stops when the element is slaved to a controller.
+    pub fn lose_audio_focus(&self) {
+        match self.controller.get() {
+            Some(controller) => controller.Pause(),
+            None => self.internal_pause_steps(),
+        }
+    }
+
     /// Marks that element as delaying the load event or not.
     ///
     /// Nothing happens if the element was already delaying the load event and
@@ -315,6 +492,8 @@ impl HTMLMediaElement {
         if !self.Paused() {
             // Step 2.1.
             self.paused.set(true);
+            self.update_controller_clock();
+            self.abandon_audio_focus();
 
             // Step 2.2.
             self.take_pending_play_promises(Err(Error::Abort));
@@ -357,6 +536,11 @@ impl HTMLMediaElement {
 
     // https://html.spec.whatwg.org/multipage/#notify-about-playing
     fn notify_about_playing(&self) {
+        // Playing is notified again when the document is thawed.
+        if document_from_node(self).is_frozen() {
+            return;
+        }
+
         // Step 1.
         self.take_pending_play_promises(Ok(()));
 
@@ -391,6 +575,7 @@ impl HTMLMediaElement {
     fn change_ready_state(&self, ready_state: ReadyState) {
         let old_ready_state = self.ready_state.get();
         self.ready_state.set(ready_state);
+        self.update_controller_clock();
 
         if self.network_state.get() == NetworkState::Empty {
             return;
@@ -452,9 +637,15 @@ impl HTMLMediaElement {
             // FIXME(nox): I have no idea what this TODO is about.
 
             // FIXME(nox): Review this block.
-            if self.autoplaying.get() && self.Paused() && self.Autoplay() {
+            if self.autoplaying.get() &&
+                self.Paused() &&
+                self.Autoplay() &&
+                self.is_allowed_to_play() &&
+                self.is_eligible_for_autoplay()
+            {
                 // Step 1
                 self.paused.set(false);
+                self.update_controller_clock();
                 // Step 2
                 if self.show_poster.get() {
                     self.show_poster.set(false);
@@ -799,6 +990,7 @@ impl HTMLMediaElement {
                     if let Err(e) = this.player.stop() {
                         eprintln!("Could not stop player {:?}", e);
                     }
+                    this.abandon_audio_focus();
 
                     // Step 6.
                     // Done after running this closure in
@@ -895,6 +1087,8 @@ impl HTMLMediaElement {
             if !self.Paused() {
                 // Step 6.6.1.
                 self.paused.set(true);
+                self.update_controller_clock();
+                self.abandon_audio_focus();
 
                 // Step 6.6.2.
                 self.take_pending_play_promises(Err(Error::Abort));
@@ -1018,6 +1212,9 @@ impl HTMLMediaElement {
         // Step 4.
         // The flag will be cleared when the media engine tells us the seek was done.
         self.seeking.set(true);
+        // A seek still pending from `thaw` now completes this one.
+        self.redrawing_frame.set(false);
+        self.update_controller_clock();
 
         // Step 5.
         // XXX(ferjm) The rest of the steps should be run in parallel, so seeking cancelation
@@ -1056,6 +1253,7 @@ impl HTMLMediaElement {
     fn seek_end(&self) {
         // Step 14.
         self.seeking.set(false);
+        self.update_controller_clock();
 
         // Step 15.
         self.time_marches_on();
@@ -1167,12 +1365,20 @@ impl HTMLMediaElement {
                 // XXX Steps 12 and 13 require audio and video tracks support.
             },
             PlayerEvent::PositionChanged(position) => {
+                // Positions reported before the player was paused on freeze
+                // must not move the element while its document is frozen.
+                if document_from_node(self).is_frozen() {
+                    return;
+                }
                 let position = position as f64;
                 let _ = self
                     .played
                     .borrow_mut()
                     .add(self.playback_position.get(), position);
                 self.playback_position.set(position);
+                if let Some(controller) = self.controller.get() {
+                    controller.sync_slave(self, position);
+                }
             },
             PlayerEvent::StateChanged(ref state) => match *state {
                 PlaybackState::Paused => {
@@ -1197,6 +1403,11 @@ impl HTMLMediaElement {
                 self.fetch_request(Some(p));
             },
             PlayerEvent::SeekDone(_) => {
+                if self.redrawing_frame.get() {
+                    self.redrawing_frame.set(false);
+                    return;
+                }
+
                 // Continuation of
                 // https://html.spec.whatwg.org/multipage/#dom-media-seek
 
@@ -1289,7 +1500,10 @@ impl HTMLMediaElementMethods for HTMLMediaElement {
     fn Play(&self) -> Rc<Promise> {
         let promise = Promise::new(&self.global());
         // Step 1.
-        // FIXME(nox): Reject promise if not allowed to play.
+        if !self.is_allowed_to_play() {
+            promise.reject_error(Error::NotAllowed);
+            return promise;
+        }
 
         // Step 2.
         if self
@@ -1321,6 +1535,7 @@ impl HTMLMediaElementMethods for HTMLMediaElement {
         if self.Paused() {
             // Step 6.1.
             self.paused.set(false);
+            self.update_controller_clock();
 
             // Step 6.2.
             if self.show_poster.get() {
@@ -1387,6 +1602,28 @@ impl HTMLMediaElementMethods for HTMLMediaElement {
         self.paused.get()
     }
 
+    // https://wicg.github.io/controls-list/#dom-htmlmediaelement-controlslist
+    fn ControlsList(&self) -> DomRoot<DOMTokenList> {
+        self.controls_list
+            .or_init(|| DOMTokenList::new(self.upcast(), &LocalName::from("controlslist")))
+    }
+
+    // https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#dom-media-controller
+    fn GetController(&self) -> Option<DomRoot<MediaController>> {
+        self.controller.get()
+    }
+
+    // https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#dom-media-controller
+    fn SetController(&self, controller: Option<&MediaController>) {
+        if let Some(previous) = self.controller.get() {
+            previous.remove_slave(self);
+        }
+        if let Some(controller) = controller {
+            controller.add_slave(self);
+        }
+        self.controller.set(controller);
+    }
+
     /// https://html.spec.whatwg.org/multipage/#dom-media-defaultplaybackrate
     fn GetDefaultPlaybackRate(&self) -> Fallible<Finite<f64>> {
         Ok(Finite::wrap(self.defaultPlaybackRate.get()))
@@ -1549,11 +1786,26 @@ impl VirtualMethods for HTMLMediaElement {
         };
     }
 
+    fn parse_plain_attribute(&self, name: &LocalName, value: DOMString) -> AttrValue {
+        if *name == LocalName::from("controlslist") {
+            return AttrValue::from_serialized_tokenlist(value.into());
+        }
+        self.super_type()
+            .unwrap()
+            .parse_plain_attribute(name, value)
+    }
+
     // https://html.spec.whatwg.org/multipage/#playing-the-media-resource:remove-an-element-from-a-document
     fn unbind_from_tree(&self, context: &UnbindContext) {
         self.super_type().unwrap().unbind_from_tree(context);
 
         if context.tree_in_doc {
+            // Elements removed from the document stop being driven by
+            // their media controller.
+            if let Some(controller) = self.controller.take() {
+                controller.remove_slave(self);
+            }
+
             let task = MediaElementMicrotask::PauseIfNotInDocumentTask {
                 elem: DomRoot::from_ref(self),
             };
@@ -4,8 +4,10 @@
 
 use crate::dom::bindings::codegen::Bindings::HTMLVideoElementBinding;
 use crate::dom::bindings::codegen::Bindings::HTMLVideoElementBinding::HTMLVideoElementMethods;
+use crate::dom::bindings::inheritance::Castable;
 use crate::dom::bindings::root::DomRoot;
 use crate::dom::document::Document;
+use crate::dom::element::Element;
 use crate::dom::htmlmediaelement::{HTMLMediaElement, ReadyState};
 use crate::dom::node::Node;
 use dom_struct::dom_struct;
@@ -82,4 +84,16 @@ impl HTMLVideoElementMethods for HTMLVideoElement {
         }
         self.video_height.get()
     }
+
+    // https://w3c.github.io/picture-in-picture/#dom-htmlvideoelement-disablepictureinpicture
+    fn DisablePictureInPicture(&self) -> bool {
+        self.upcast::<Element>()
+            .has_attribute(&LocalName::from("disablepictureinpicture"))
+    }
+
+    // https://w3c.github.io/picture-in-picture/#dom-htmlvideoelement-disablepictureinpicture
+    fn SetDisablePictureInPicture(&self, value: bool) {
+        self.upcast::<Element>()
+            .set_bool_attribute(&LocalName::from("disablepictureinpicture"), value)
+    }
 }
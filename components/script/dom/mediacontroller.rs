@@ -0,0 +1,218 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use crate::dom::bindings::cell::DomRefCell;
+use crate::dom::bindings::codegen::Bindings::HTMLMediaElementBinding::HTMLMediaElementMethods;
+use crate::dom::bindings::codegen::Bindings::MediaControllerBinding::{
+    self, MediaControllerMethods,
+};
+use crate::dom::bindings::error::Fallible;
+use crate::dom::bindings::inheritance::Castable;
+use crate::dom::bindings::num::Finite;
+use crate::dom::bindings::reflector::{reflect_dom_object, DomObject};
+use crate::dom::bindings::root::{Dom, DomRoot};
+use crate::dom::eventtarget::EventTarget;
+use crate::dom::htmlmediaelement::{HTMLMediaElement, ReadyState};
+use crate::dom::window::Window;
+use dom_struct::dom_struct;
+use servo_atoms::Atom;
+use std::cell::Cell;
+use time::precise_time_ns;
+
+/// How far, in seconds, a slaved media element may drift away from the
+/// media controller position before it is seeked back to it.
+const MAX_SLAVE_DRIFT: f64 = 0.25;
+
+/// A controller that drives the playback of several media elements in
+/// lockstep: playing, pausing and seeking the controller applies the same
+/// operation to every slaved media element.
+#[dom_struct]
+pub struct MediaController {
+    eventtarget: EventTarget,
+    /// The media elements whose playback is driven by this controller.
+    slaves: DomRefCell<Vec<Dom<HTMLMediaElement>>>,
+    /// https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#dom-mediacontroller-paused
+    paused: Cell<bool>,
+    /// The media controller position as of `position_timestamp`.
+    /// https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#media-controller-position
+    position: Cell<f64>,
+    /// The time, in nanoseconds, at which `position` was last updated.
+    position_timestamp: Cell<u64>,
+    /// Whether the media controller position is currently advancing, i.e.
+    /// the controller is not blocked.
+    running: Cell<bool>,
+}
+
+impl MediaController {
+    fn new_inherited() -> MediaController {
+        MediaController {
+            eventtarget: EventTarget::new_inherited(),
+            slaves: DomRefCell::new(vec![]),
+            paused: Cell::new(false),
+            position: Cell::new(0.),
+            position_timestamp: Cell::new(precise_time_ns()),
+            running: Cell::new(false),
+        }
+    }
+
+    pub fn new(window: &Window) -> DomRoot<MediaController> {
+        reflect_dom_object(
+            Box::new(MediaController::new_inherited()),
+            window,
+            MediaControllerBinding::Wrap,
+        )
+    }
+
+    pub fn Constructor(window: &Window) -> Fallible<DomRoot<MediaController>> {
+        Ok(MediaController::new(window))
+    }
+
+    /// Slaves `element` to this controller.
+    pub fn add_slave(&self, element: &HTMLMediaElement) {
+        if self.slaves.borrow().iter().any(|slave| *slave == element) {
+            return;
+        }
+        self.slaves.borrow_mut().push(Dom::from_ref(element));
+        self.update_clock();
+    }
+
+    /// Stops driving `element` from this controller.
+    pub fn remove_slave(&self, element: &HTMLMediaElement) {
+        self.slaves.borrow_mut().retain(|slave| *slave != element);
+        self.update_clock();
+    }
+
+    pub fn slaves(&self) -> Vec<DomRoot<HTMLMediaElement>> {
+        self.slaves
+            .borrow()
+            .iter()
+            .map(|slave| DomRoot::from_ref(&**slave))
+            .collect()
+    }
+
+    /// https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#media-controller-position
+    ///
+    /// The position advances with the wall clock while the controller is
+    /// running, independently of the positions reported by its slaves.
+    fn position(&self) -> f64 {
+        let position = self.position.get();
+        if !self.running.get() {
+            return position;
+        }
+        let elapsed = (precise_time_ns() - self.position_timestamp.get()) as f64 / 1_000_000_000.;
+        (position + elapsed).min(self.Duration())
+    }
+
+    fn set_position(&self, position: f64) {
+        self.position.set(position);
+        self.position_timestamp.set(precise_time_ns());
+    }
+
+    /// https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#blocked-media-controller
+    ///
+    /// The controller is blocked while it is paused, while any of its slaves
+    /// is still buffering or seeking, or while all of them are paused.
+    fn is_blocked(&self) -> bool {
+        if self.paused.get() {
+            return true;
+        }
+        let slaves = self.slaves.borrow();
+        let slave_blocked = slaves
+            .iter()
+            .any(|slave| slave.get_ready_state() < ReadyState::HaveFutureData || slave.Seeking());
+        slave_blocked || slaves.iter().all(|slave| slave.Paused())
+    }
+
+    /// Starts or stops the media controller position depending on whether
+    /// the controller is blocked. Called whenever the controller or one of
+    /// its slaves changes its paused, ready or seeking state.
+    pub fn update_clock(&self) {
+        self.set_position(self.position());
+        self.running.set(!self.is_blocked());
+    }
+
+    /// Called whenever a slaved media element reports a new playback
+    /// position. Seeks the element back to the media controller position
+    /// if it drifted too far away from it.
+    pub fn sync_slave(&self, slave: &HTMLMediaElement, slave_position: f64) {
+        if self.is_blocked() || slave.Paused() {
+            return;
+        }
+        let position = self.position();
+        if (slave_position - position).abs() > MAX_SLAVE_DRIFT {
+            slave.SetCurrentTime(Finite::wrap(position));
+        }
+    }
+
+    fn queue_simple_event(&self, name: Atom) {
+        let global = self.global();
+        let window = global.as_window();
+        window
+            .task_manager()
+            .media_element_task_source()
+            .queue_simple_event(self.upcast(), name, window);
+    }
+}
+
+impl MediaControllerMethods for MediaController {
+    // https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#dom-mediacontroller-duration
+    fn Duration(&self) -> f64 {
+        self.slaves()
+            .iter()
+            .map(|slave| slave.Duration())
+            .filter(|duration| !duration.is_nan())
+            .fold(0., f64::max)
+    }
+
+    // https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#dom-mediacontroller-currenttime
+    fn CurrentTime(&self) -> Finite<f64> {
+        Finite::wrap(self.position())
+    }
+
+    // https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#dom-mediacontroller-currenttime
+    fn SetCurrentTime(&self, time: Finite<f64>) {
+        let position = (*time).max(0.).min(self.Duration());
+        self.set_position(position);
+        for slave in self.slaves() {
+            slave.SetCurrentTime(Finite::wrap(position));
+        }
+    }
+
+    // https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#dom-mediacontroller-paused
+    fn Paused(&self) -> bool {
+        self.paused.get()
+    }
+
+    // https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#dom-mediacontroller-play
+    fn Play(&self) {
+        for slave in self.slaves() {
+            let _ = slave.Play();
+        }
+
+        if self.paused.get() {
+            self.paused.set(false);
+            self.queue_simple_event(atom!("play"));
+        }
+        self.update_clock();
+    }
+
+    // https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#dom-mediacontroller-pause
+    fn Pause(&self) {
+        for slave in self.slaves() {
+            slave.Pause();
+        }
+
+        if !self.paused.get() {
+            self.paused.set(true);
+            self.queue_simple_event(atom!("pause"));
+        }
+        self.update_clock();
+    }
+
+    // https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#handler-mediacontroller-onplay
+    event_handler!(play, GetOnplay, SetOnplay);
+
+    // https://www.w3.org/TR/2016/REC-html51-20161101/semantics-embedded-content.html#handler-mediacontroller-onpause
+    event_handler!(pause, GetOnpause, SetOnpause);
+}
@@ -244,6 +244,7 @@ pub mod canvasgradient;
 pub mod canvaspattern;
 pub mod canvasrenderingcontext2d;
 pub mod channelmergernode;
+pub mod channelsplitternode;
 pub mod characterdata;
 pub mod client;
 pub mod closeevent;
@@ -387,6 +388,7 @@ pub mod imagedata;
 pub mod inputevent;
 pub mod keyboardevent;
 pub mod location;
+pub mod mediacontroller;
 pub mod mediaerror;
 pub mod medialist;
 pub mod mediaquerylist;
@@ -8,6 +8,7 @@ use crate::dom::bindings::codegen::Bindings::PermissionStatusBinding::{
     PermissionName, PermissionState,
 };
 use crate::dom::bindings::codegen::Bindings::PermissionsBinding::{self, PermissionsMethods};
+use crate::dom::bindings::codegen::Bindings::WindowBinding::WindowMethods;
 use crate::dom::bindings::error::Error;
 use crate::dom::bindings::reflector::{reflect_dom_object, DomObject, Reflector};
 use crate::dom::bindings::root::DomRoot;
@@ -16,7 +17,10 @@ use crate::dom::bluetoothpermissionresult::BluetoothPermissionResult;
 use crate::dom::globalscope::GlobalScope;
 use crate::dom::permissionstatus::PermissionStatus;
 use crate::dom::promise::Promise;
+use crate::dom::window::Window;
 use dom_struct::dom_struct;
+use embedder_traits::{EmbedderMsg, PermissionName as EmbedderPermissionName};
+use ipc_channel::ipc;
 use js::conversions::ConversionResult;
 use js::jsapi::{JSContext, JSObject};
 use js::jsval::{ObjectValue, UndefinedValue};
@@ -299,6 +303,16 @@ pub fn get_descriptor_permission_state(
         None => GlobalScope::current().expect("No current global object"),
     };
 
+    // Decisions the user already made are kept by the embedder.
+    let stored = match permission_name {
+        PermissionName::Autoplay => Some(settings.as_window().Document().is_autoplay_allowed()),
+        _ => embedder_permission_name(&permission_name)
+            .and_then(|name| query_embedder_permission_store(settings.as_window(), name)),
+    };
+    if let Some(state) = stored_permission_state(stored) {
+        return state;
+    }
+
     // Step 2.
     // TODO: The `is the environment settings object a non-secure context` check is missing.
     // The current solution is a workaround with a message box to warn about this,
@@ -347,6 +361,40 @@ pub fn get_descriptor_permission_state(
     state
 }
 
+/// Asks the embedder permission store whether `name` was granted or denied.
+/// Returns `None` if the user has not decided yet.
+pub fn query_embedder_permission_store(
+    window: &Window,
+    name: EmbedderPermissionName,
+) -> Option<bool> {
+    let (sender, receiver) = ipc::channel().unwrap();
+    window.send_to_embedder(EmbedderMsg::QueryPermission(name, sender));
+    receiver.recv().unwrap_or(None)
+}
+
+/// Maps a permission name to the one the embedder permission store knows,
+/// if the embedder keeps decisions for it.
+pub fn embedder_permission_name(
+    permission_name: &PermissionName,
+) -> Option<EmbedderPermissionName> {
+    match *permission_name {
+        PermissionName::Camera => Some(EmbedderPermissionName::Camera),
+        PermissionName::Microphone => Some(EmbedderPermissionName::Microphone),
+        PermissionName::Persistent_storage => Some(EmbedderPermissionName::PersistentStorage),
+        PermissionName::Autoplay => Some(EmbedderPermissionName::Autoplay),
+        _ => None,
+    }
+}
+
+/// The permission state for a decision kept in the embedder permission store.
+pub fn stored_permission_state(stored: Option<bool>) -> Option<PermissionState> {
+    match stored {
+        Some(true) => Some(PermissionState::Granted),
+        Some(false) => Some(PermissionState::Denied),
+        None => None,
+    }
+}
+
 #[cfg(target_os = "linux")]
 fn prompt_user(message: &str) -> PermissionState {
     if opts::get().headless {
@@ -394,5 +442,7 @@ fn allowed_in_nonsecure_contexts(permission_name: &PermissionName) -> bool {
         PermissionName::Bluetooth => false,
         // https://storage.spec.whatwg.org/#dom-permissionname-persistent-storage
         PermissionName::Persistent_storage => false,
+        // https://w3c.github.io/autoplay/
+        PermissionName::Autoplay => true,
     }
 }
@@ -14,7 +14,7 @@ use dom_struct::dom_struct;
 use std::fmt;
 use std::rc::Rc;
 
-#[derive(JSTraceable, MallocSizeOf)]
+#[derive(Clone, JSTraceable, MallocSizeOf)]
 struct TimeRange {
     start: f64,
     end: f64,
@@ -57,7 +57,7 @@ pub enum TimeRangesError {
     OutOfRange,
 }
 
-#[derive(Debug, JSTraceable, MallocSizeOf)]
+#[derive(Clone, Debug, JSTraceable, MallocSizeOf)]
 pub struct TimeRangesContainer {
     ranges: Vec<TimeRange>,
 }
@@ -123,6 +123,64 @@ impl TimeRangesContainer {
 
         Ok(())
     }
+
+    /// Whether `time` falls within any of the ranges.
+    pub fn contains(&self, time: f64) -> bool {
+        self.ranges.iter().any(|range| range.contains(time))
+    }
+
+    /// Returns the ranges covered by either `self` or `other`.
+    pub fn union(&self, other: &TimeRangesContainer) -> TimeRangesContainer {
+        let mut result = self.clone();
+        for range in &other.ranges {
+            // Ranges in a container are always well-formed, so this can't fail.
+            let _ = result.add(range.start, range.end);
+        }
+        result
+    }
+
+    /// Returns the ranges covered by both `self` and `other`.
+    pub fn intersection(&self, other: &TimeRangesContainer) -> TimeRangesContainer {
+        let mut result = TimeRangesContainer::new();
+
+        // Both lists are sorted and free of overlaps, so we can walk them
+        // in lockstep, always advancing the range that ends first.
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = f64::max(a.start, b.start);
+            let end = f64::min(a.end, b.end);
+            if start < end {
+                result.ranges.push(TimeRange { start, end });
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        result
+    }
+
+    /// Merges the ranges that are separated by gaps no longer than
+    /// `tolerance` seconds.
+    ///
+    /// Media backends usually report buffered data with small holes between
+    /// consecutive frames, which must not be exposed as distinct ranges.
+    pub fn normalize(&mut self, tolerance: f64) {
+        let mut normalized: Vec<TimeRange> = Vec::with_capacity(self.ranges.len());
+        for range in self.ranges.drain(..) {
+            if let Some(last) = normalized.last_mut() {
+                if range.start - last.end <= tolerance {
+                    last.union(&range);
+                    continue;
+                }
+            }
+            normalized.push(range);
+        }
+        self.ranges = normalized;
+    }
 }
 
 #[dom_struct]
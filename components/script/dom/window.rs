@@ -106,6 +106,7 @@ use script_traits::{ScriptMsg, ScriptToConstellationChan, ScrollState, TimerEven
 use script_traits::{TimerSchedulerMsg, UntrustedNodeAddress, WindowSizeData, WindowSizeType};
 use selectors::attr::CaseSensitivity;
 use servo_config::opts;
+use servo_config::prefs::PREFS;
 use servo_geometry::{f32_rect_to_au_rect, MaxRect};
 use servo_url::{Host, ImmutableOrigin, MutableOrigin, ServoUrl};
 use std::borrow::ToOwned;
@@ -114,6 +115,7 @@ use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
 use std::default::Default;
 use std::env;
+use std::f64;
 use std::fs;
 use std::io::{stderr, stdout, Write};
 use std::mem;
@@ -132,6 +134,10 @@ use url::Position;
 use webrender_api::{DeviceIntPoint, DeviceIntSize, DocumentId, ExternalScrollId, RenderApiSender};
 use webvr_traits::WebVRMsg;
 
+/// How long, in milliseconds, an activation notification grants transient activation.
+/// <https://html.spec.whatwg.org/multipage/#transient-activation-duration>
+const TRANSIENT_ACTIVATION_DURATION_MS: f64 = 5000.;
+
 /// Current state of the window object
 #[derive(Clone, Copy, Debug, JSTraceable, MallocSizeOf, PartialEq)]
 enum WindowState {
@@ -293,6 +299,11 @@ pub struct Window {
     /// Indicate whether a SetDocumentStatus message has been sent after a reflow is complete.
     /// It is used to avoid sending idle message more than once, which is unneccessary.
     has_sent_idle_message: Cell<bool>,
+
+    /// <https://html.spec.whatwg.org/multipage/#last-activation-timestamp>
+    /// Positive infinity if the window never had user activation, negative
+    /// infinity once its transient activation has been consumed.
+    last_activation_timestamp: Cell<f64>,
 }
 
 impl Window {
@@ -417,6 +428,51 @@ impl Window {
         &self.permission_state_invocation_results
     }
 
+    /// <https://html.spec.whatwg.org/multipage/#activation-notification>
+    pub fn notify_user_activation(&self) {
+        // TODO: Also notify ancestor and same origin descendant windows.
+        self.last_activation_timestamp
+            .set(time::precise_time_ns() as f64 / 1_000_000.);
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#sticky-activation>
+    pub fn has_sticky_activation(&self) -> bool {
+        self.last_activation_timestamp.get() != f64::INFINITY
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#transient-activation>
+    pub fn has_transient_activation(&self) -> bool {
+        let now = time::precise_time_ns() as f64 / 1_000_000.;
+        let elapsed = now - self.last_activation_timestamp.get();
+        elapsed >= 0. && elapsed < TRANSIENT_ACTIVATION_DURATION_MS
+    }
+
+    /// <https://html.spec.whatwg.org/multipage/#consume-user-activation>
+    pub fn consume_user_activation(&self) {
+        // TODO: Consume the activation of every window in the top-level
+        // browsing context's tree, not just this one.
+        if self.last_activation_timestamp.get() != f64::INFINITY {
+            self.last_activation_timestamp.set(f64::NEG_INFINITY);
+        }
+    }
+
+    /// Whether an API gated on user activation may proceed. Gating is only
+    /// enforced when the `dom.user_activation.enabled` pref is set.
+    pub fn is_allowed_by_user_activation(&self, transient: bool) -> bool {
+        if !PREFS
+            .get("dom.user_activation.enabled")
+            .as_boolean()
+            .unwrap_or(false)
+        {
+            return true;
+        }
+        if transient {
+            self.has_transient_activation()
+        } else {
+            self.has_sticky_activation()
+        }
+    }
+
     pub fn pending_image_notification(&self, response: PendingImageResponse) {
         //XXXjdm could be more efficient to send the responses to the layout thread,
         //       rather than making the layout thread talk to the image cache to
@@ -2108,6 +2164,7 @@ impl Window {
             exists_mut_observer: Cell::new(false),
             webrender_api_sender,
             has_sent_idle_message: Cell::new(false),
+            last_activation_timestamp: Cell::new(f64::INFINITY),
         });
 
         unsafe { WindowBinding::Wrap(runtime.cx(), win) }
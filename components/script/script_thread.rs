@@ -50,6 +50,7 @@ use crate::dom::event::{Event, EventBubbles, EventCancelable};
 use crate::dom::globalscope::GlobalScope;
 use crate::dom::htmlanchorelement::HTMLAnchorElement;
 use crate::dom::htmliframeelement::{HTMLIFrameElement, NavigationType};
+use crate::dom::htmlmediaelement::HTMLMediaElement;
 use crate::dom::mutationobserver::MutationObserver;
 use crate::dom::node::{from_untrusted_node_address, window_from_node, Node, NodeDamage};
 use crate::dom::performanceentry::PerformanceEntry;
@@ -581,6 +582,9 @@ pub struct ScriptThread {
     /// The topmost element over the mouse.
     topmost_mouse_over_target: MutNullableDom<Element>,
 
+    /// The media element currently holding audio focus, if any.
+    audio_focus_holder: MutNullableDom<HTMLMediaElement>,
+
     /// List of pipelines that have been owned and closed by this script thread.
     closed_pipelines: DomRefCell<HashSet<PipelineId>>,
 
@@ -779,6 +783,21 @@ impl ScriptThread {
         })
     }
 
+    /// The media element of this script thread holding the audio focus, if any.
+    pub fn audio_focus_holder() -> Option<DomRoot<HTMLMediaElement>> {
+        SCRIPT_THREAD_ROOT.with(|root| {
+            let script_thread = unsafe { &*root.get().unwrap() };
+            script_thread.audio_focus_holder.get()
+        })
+    }
+
+    pub fn set_audio_focus_holder(element: Option<&HTMLMediaElement>) {
+        SCRIPT_THREAD_ROOT.with(|root| {
+            let script_thread = unsafe { &*root.get().unwrap() };
+            script_thread.audio_focus_holder.set(element);
+        })
+    }
+
     pub fn mark_document_with_no_blocked_loads(doc: &Document) {
         SCRIPT_THREAD_ROOT.with(|root| {
             let script_thread = unsafe { &*root.get().unwrap() };
@@ -1082,6 +1101,7 @@ impl ScriptThread {
 
             js_runtime: Rc::new(runtime),
             topmost_mouse_over_target: MutNullableDom::new(Default::default()),
+            audio_focus_holder: Default::default(),
             closed_pipelines: DomRefCell::new(HashSet::new()),
 
             scheduler_chan: state.scheduler_chan,
@@ -1416,6 +1436,7 @@ impl ScriptThread {
                     SetScrollState(id, ..) => Some(id),
                     GetTitle(id) => Some(id),
                     SetDocumentActivity(id, ..) => Some(id),
+                    SetDocumentFrozen(id, ..) => Some(id),
                     ChangeFrameVisibilityStatus(id, ..) => Some(id),
                     NotifyVisibilityChange(id, ..) => Some(id),
                     Navigate(id, ..) => Some(id),
@@ -1439,6 +1460,7 @@ impl ScriptThread {
                     Reload(id, ..) => Some(id),
                     WebVREvents(id, ..) => Some(id),
                     PaintMetric(..) => None,
+                    RevokeAudioFocus(id) => Some(id),
                 }
             },
             MixedMessage::FromDevtools(_) => None,
@@ -1580,6 +1602,9 @@ impl ScriptThread {
             ConstellationControlMsg::SetDocumentActivity(pipeline_id, activity) => {
                 self.handle_set_document_activity_msg(pipeline_id, activity)
             },
+            ConstellationControlMsg::SetDocumentFrozen(pipeline_id, frozen) => {
+                self.handle_set_document_frozen_msg(pipeline_id, frozen)
+            },
             ConstellationControlMsg::ChangeFrameVisibilityStatus(pipeline_id, visible) => {
                 self.handle_visibility_change_msg(pipeline_id, visible)
             },
@@ -1663,6 +1688,9 @@ impl ScriptThread {
             ConstellationControlMsg::PaintMetric(pipeline_id, metric_type, metric_value) => {
                 self.handle_paint_metric(pipeline_id, metric_type, metric_value)
             },
+            ConstellationControlMsg::RevokeAudioFocus(pipeline_id) => {
+                self.handle_revoke_audio_focus(pipeline_id)
+            },
             msg @ ConstellationControlMsg::AttachLayout(..) |
             msg @ ConstellationControlMsg::Viewport(..) |
             msg @ ConstellationControlMsg::SetScrollState(..) |
@@ -2068,6 +2096,13 @@ impl ScriptThread {
         warn!("change of activity sent to nonexistent pipeline");
     }
 
+    fn handle_set_document_frozen_msg(&self, id: PipelineId, frozen: bool) {
+        match self.documents.borrow().find_document(id) {
+            Some(document) => document.set_frozen(frozen),
+            None => warn!("freezing nonexistent pipeline {}", id),
+        }
+    }
+
     fn handle_focus_iframe_msg(
         &self,
         parent_pipeline_id: PipelineId,
@@ -2410,6 +2445,14 @@ impl ScriptThread {
                 }
             }
 
+            // The constellation releases the audio focus of exited pipelines,
+            // only the root held here needs to go.
+            if let Some(holder) = self.audio_focus_holder.get() {
+                if holder.upcast::<Node>().owner_doc() == document {
+                    self.audio_focus_holder.set(None);
+                }
+            }
+
             // We discard the browsing context after requesting layout shut down,
             // to avoid running layout on detached iframes.
             let window = document.window();
@@ -3392,6 +3435,20 @@ impl ScriptThread {
         }
     }
 
+    /// Another pipeline, or the embedder, took the audio focus away from the
+    /// media of `pipeline_id`.
+    fn handle_revoke_audio_focus(&self, pipeline_id: PipelineId) {
+        let holder = match self.audio_focus_holder.get() {
+            Some(holder) => holder,
+            None => return,
+        };
+        if holder.upcast::<Node>().owner_doc().window().pipeline_id() != Some(pipeline_id) {
+            return;
+        }
+        self.audio_focus_holder.set(None);
+        holder.lose_audio_focus();
+    }
+
     pub fn enqueue_microtask(job: Microtask) {
         SCRIPT_THREAD_ROOT.with(|root| {
             let script_thread = unsafe { &*root.get().unwrap() };
@@ -15,6 +15,14 @@ pub mod area {
     pub use crate::dom::htmlareaelement::{Area, Shape};
 }
 
+pub mod permissions {
+    pub use crate::dom::bindings::codegen::Bindings::PermissionStatusBinding::{
+        PermissionName, PermissionState,
+    };
+    pub use crate::dom::permissions::{embedder_permission_name, stored_permission_state};
+    pub use embedder_traits::PermissionName as EmbedderPermissionName;
+}
+
 pub mod size_of {
     use crate::dom::characterdata::CharacterData;
     use crate::dom::element::Element;
@@ -275,6 +275,9 @@ pub enum ConstellationControlMsg {
     GetTitle(PipelineId),
     /// Notifies script thread of a change to one of its document's activity
     SetDocumentActivity(PipelineId, DocumentActivity),
+    /// Notifies script thread that a fully active document must be frozen or resumed,
+    /// e.g. while the embedder is suspended by the platform.
+    SetDocumentFrozen(PipelineId, bool),
     /// Notifies script thread whether frame is visible
     ChangeFrameVisibilityStatus(PipelineId, bool),
     /// Notifies script thread that frame visibility change is complete
@@ -347,6 +350,9 @@ pub enum ConstellationControlMsg {
     WebVREvents(PipelineId, Vec<WebVREvent>),
     /// Notifies the script thread about a new recorded paint metric.
     PaintMetric(PipelineId, ProgressiveWebMetricType, u64),
+    /// Notifies the script thread that media in the given pipeline lost the audio focus
+    /// and must stop playing.
+    RevokeAudioFocus(PipelineId),
 }
 
 impl fmt::Debug for ConstellationControlMsg {
@@ -366,6 +372,7 @@ impl fmt::Debug for ConstellationControlMsg {
             SetScrollState(..) => "SetScrollState",
             GetTitle(..) => "GetTitle",
             SetDocumentActivity(..) => "SetDocumentActivity",
+            SetDocumentFrozen(..) => "SetDocumentFrozen",
             ChangeFrameVisibilityStatus(..) => "ChangeFrameVisibilityStatus",
             NotifyVisibilityChange(..) => "NotifyVisibilityChange",
             Navigate(..) => "Navigate",
@@ -384,6 +391,7 @@ impl fmt::Debug for ConstellationControlMsg {
             Reload(..) => "Reload",
             WebVREvents(..) => "WebVREvents",
             PaintMetric(..) => "PaintMetric",
+            RevokeAudioFocus(..) => "RevokeAudioFocus",
         };
         write!(formatter, "ConstellationControlMsg::{}", variant)
     }
@@ -774,6 +782,10 @@ pub enum ConstellationMsg {
     ForwardEvent(PipelineId, CompositorEvent),
     /// Requesting a change to the onscreen cursor.
     SetCursor(CursorKind),
+    /// The platform took the audio focus away from a top level browsing context.
+    RevokeAudioFocus(TopLevelBrowsingContextId),
+    /// Freeze or resume the pages of a top level browsing context.
+    SetPageFrozen(TopLevelBrowsingContextId, bool),
 }
 
 impl fmt::Debug for ConstellationMsg {
@@ -801,6 +813,8 @@ impl fmt::Debug for ConstellationMsg {
             SelectBrowser(..) => "SelectBrowser",
             ForwardEvent(..) => "ForwardEvent",
             SetCursor(..) => "SetCursor",
+            RevokeAudioFocus(..) => "RevokeAudioFocus",
+            SetPageFrozen(..) => "SetPageFrozen",
         };
         write!(formatter, "ConstellationMsg::{}", variant)
     }
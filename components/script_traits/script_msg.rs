@@ -199,6 +199,11 @@ pub enum ScriptMsg {
     GetScreenSize(IpcSender<(DeviceIntSize)>),
     /// Get the available screen size (pixel)
     GetScreenAvailSize(IpcSender<(DeviceIntSize)>),
+    /// Notifies the constellation that media in this pipeline started playing audibly
+    /// and takes the audio focus.
+    RequestAudioFocus,
+    /// Notifies the constellation that media in this pipeline no longer needs the audio focus.
+    AbandonAudioFocus,
 }
 
 impl fmt::Debug for ScriptMsg {
@@ -244,6 +249,8 @@ impl fmt::Debug for ScriptMsg {
             GetClientWindow(..) => "GetClientWindow",
             GetScreenSize(..) => "GetScreenSize",
             GetScreenAvailSize(..) => "GetScreenAvailSize",
+            RequestAudioFocus => "RequestAudioFocus",
+            AbandonAudioFocus => "AbandonAudioFocus",
         };
         write!(formatter, "ScriptMsg::{}", variant)
     }
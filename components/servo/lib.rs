@@ -360,6 +360,26 @@ where
                 self.compositor.capture_webrender();
             },
 
+            WindowEvent::RevokeAudioFocus(top_level_browsing_context_id) => {
+                let msg = ConstellationMsg::RevokeAudioFocus(top_level_browsing_context_id);
+                if let Err(e) = self.constellation_chan.send(msg) {
+                    warn!(
+                        "Sending RevokeAudioFocus message to constellation failed ({:?}).",
+                        e
+                    );
+                }
+            },
+
+            WindowEvent::SetPageFrozen(top_level_browsing_context_id, frozen) => {
+                let msg = ConstellationMsg::SetPageFrozen(top_level_browsing_context_id, frozen);
+                if let Err(e) = self.constellation_chan.send(msg) {
+                    warn!(
+                        "Sending SetPageFrozen message to constellation failed ({:?}).",
+                        e
+                    );
+                }
+            },
+
             WindowEvent::NewBrowser(url, browser_id) => {
                 let msg = ConstellationMsg::NewBrowser(url, browser_id);
                 if let Err(e) = self.constellation_chan.send(msg) {
@@ -335,7 +335,7 @@ ${helpers.single_keyword(
 ${helpers.single_keyword(
     "object-fit",
     "fill contain cover none scale-down",
-    products="gecko",
+    products="gecko servo",
     animation_value_type="discrete",
     spec="https://drafts.csswg.org/css-images/#propdef-object-fit",
 )}
@@ -344,7 +344,7 @@ ${helpers.predefined_type(
     "object-position",
     "Position",
     "computed::Position::zero()",
-    products="gecko",
+    products="gecko servo",
     boxed=True,
     spec="https://drafts.csswg.org/css-images-3/#the-object-position",
     animation_value_type="ComputedValue",
@@ -177,6 +177,9 @@ pub unsafe extern "C" fn heartbeat_servo(servo: *mut ServoInstance) {
                 EmbedderMsg::AllowUnload(sender) => {
                     let _ = sender.send(true);
                 },
+                EmbedderMsg::QueryPermission(_, sender) => {
+                    let _ = sender.send(None);
+                },
                 EmbedderMsg::Alert(_, sender) => {
                     let _ = sender.send(());
                 },
@@ -215,6 +218,7 @@ pub unsafe extern "C" fn heartbeat_servo(servo: *mut ServoInstance) {
                 EmbedderMsg::NewFavicon(..) |
                 EmbedderMsg::HeadParsed |
                 EmbedderMsg::SetFullscreenState(..) |
+                EmbedderMsg::SetAudioFocus(..) |
                 EmbedderMsg::Shutdown |
                 EmbedderMsg::Panic(..) => {},
             }
@@ -82,6 +82,10 @@ pub trait HostTrait {
     /// has events for Servo, or Servo has woken up the embedder event loop via
     /// EventLoopWaker).
     fn on_animating_changed(&self, animating: bool);
+    /// Audible media started or stopped playing. The embedder should acquire
+    /// or abandon the platform audio focus accordingly, and call
+    /// `revoke_audio_focus` when the platform takes the focus away.
+    fn on_audio_focus_changed(&self, focused: bool);
     /// Servo finished shutting down.
     fn on_shutdown_complete(&self);
 }
@@ -239,6 +243,22 @@ impl ServoGlue {
         self.process_event(event)
     }
 
+    /// Freeze the page while the application is suspended, or resume it.
+    pub fn set_page_frozen(&mut self, frozen: bool) -> Result<(), &'static str> {
+        info!("set_page_frozen {}", frozen);
+        let browser_id = self.get_browser_id()?;
+        let event = WindowEvent::SetPageFrozen(browser_id, frozen);
+        self.process_event(event)
+    }
+
+    /// The platform took the audio focus away: pause the audible media.
+    pub fn revoke_audio_focus(&mut self) -> Result<(), &'static str> {
+        info!("revoke_audio_focus");
+        let browser_id = self.get_browser_id()?;
+        let event = WindowEvent::RevokeAudioFocus(browser_id);
+        self.process_event(event)
+    }
+
     /// Redraw the page.
     pub fn refresh(&mut self) -> Result<(), &'static str> {
         info!("refresh");
@@ -437,6 +457,14 @@ impl ServoGlue {
                 EmbedderMsg::AllowUnload(sender) => {
                     let _ = sender.send(true);
                 },
+                EmbedderMsg::QueryPermission(_, sender) => {
+                    let _ = sender.send(None);
+                },
+                EmbedderMsg::SetAudioFocus(focused) => {
+                    self.callbacks
+                        .host_callbacks
+                        .on_audio_focus_changed(focused);
+                },
                 EmbedderMsg::Alert(message, sender) => {
                     info!("Alert: {}", message);
                     let _ = sender.send(());
@@ -40,6 +40,7 @@ pub struct CHostCallbacks {
     pub on_url_changed: extern "C" fn(url: *const c_char),
     pub on_history_changed: extern "C" fn(can_go_back: bool, can_go_forward: bool),
     pub on_animating_changed: extern "C" fn(animating: bool),
+    pub on_audio_focus_changed: extern "C" fn(focused: bool),
     pub on_shutdown_complete: extern "C" fn(),
 }
 
@@ -161,6 +162,18 @@ pub extern "C" fn reload() {
     call(|s| s.reload());
 }
 
+#[no_mangle]
+pub extern "C" fn set_page_frozen(frozen: bool) {
+    debug!("set_page_frozen");
+    call(|s| s.set_page_frozen(frozen));
+}
+
+#[no_mangle]
+pub extern "C" fn revoke_audio_focus() {
+    debug!("revoke_audio_focus");
+    call(|s| s.revoke_audio_focus());
+}
+
 #[no_mangle]
 pub extern "C" fn stop() {
     debug!("stop");
@@ -342,6 +355,11 @@ impl HostTrait for HostCallbacks {
         (self.0.on_animating_changed)(animating);
     }
 
+    fn on_audio_focus_changed(&self, focused: bool) {
+        debug!("on_audio_focus_changed");
+        (self.0.on_audio_focus_changed)(focused);
+    }
+
     fn on_shutdown_complete(&self) {
         debug!("on_shutdown_complete");
         (self.0.on_shutdown_complete)();
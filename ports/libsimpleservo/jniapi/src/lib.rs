@@ -166,6 +166,18 @@ pub fn Java_org_mozilla_servoview_JNIServo_reload(env: JNIEnv, _class: JClass) {
     call(&env, |s| s.reload());
 }
 
+#[no_mangle]
+pub fn Java_org_mozilla_servoview_JNIServo_setPageFrozen(env: JNIEnv, _: JClass, frozen: jboolean) {
+    debug!("setPageFrozen");
+    call(&env, |s| s.set_page_frozen(frozen == JNI_TRUE));
+}
+
+#[no_mangle]
+pub fn Java_org_mozilla_servoview_JNIServo_revokeAudioFocus(env: JNIEnv, _class: JClass) {
+    debug!("revokeAudioFocus");
+    call(&env, |s| s.revoke_audio_focus());
+}
+
 #[no_mangle]
 pub fn Java_org_mozilla_servoview_JNIServo_stop(env: JNIEnv, _class: JClass) {
     debug!("stop");
@@ -488,6 +500,19 @@ impl HostTrait for HostCallbacks {
         )
         .unwrap();
     }
+
+    fn on_audio_focus_changed(&self, focused: bool) {
+        debug!("on_audio_focus_changed");
+        let env = self.jvm.get_env().unwrap();
+        let focused = JValue::Bool(focused as jboolean);
+        env.call_method(
+            self.callbacks.as_obj(),
+            "onAudioFocusChanged",
+            "(Z)V",
+            &[focused],
+        )
+        .unwrap();
+    }
 }
 
 fn initialize_android_glue(env: &JNIEnv, activity: JObject) {
@@ -330,6 +330,9 @@ impl Browser {
                 EmbedderMsg::SetFullscreenState(state) => {
                     self.window.set_fullscreen(state);
                 },
+                EmbedderMsg::SetAudioFocus(focused) => {
+                    debug!("SetAudioFocus received ({})", focused);
+                },
                 EmbedderMsg::LoadStart => {
                     self.loading_state = Some(LoadingState::Connecting);
                 },
@@ -359,6 +362,13 @@ impl Browser {
                         self.event_queue.push(WindowEvent::SendError(None, reason));
                     };
                 },
+                EmbedderMsg::QueryPermission(_, sender) => {
+                    // There is no permission store yet, let script prompt the user.
+                    if let Err(e) = sender.send(None) {
+                        let reason = format!("Failed to send QueryPermission response: {}", e);
+                        self.event_queue.push(WindowEvent::SendError(None, reason));
+                    };
+                },
                 EmbedderMsg::SelectFiles(patterns, multiple_files, sender) => {
                     let res = match (
                         opts::get().headless,
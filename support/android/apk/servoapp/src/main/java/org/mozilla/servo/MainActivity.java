@@ -8,6 +8,7 @@ package org.mozilla.servo;
 import android.app.Activity;
 import android.content.Context;
 import android.content.Intent;
+import android.media.AudioManager;
 import android.net.Uri;
 import android.os.Bundle;
 import android.system.ErrnoException;
@@ -39,6 +40,8 @@ public class MainActivity extends Activity implements Servo.Client {
     ProgressBar mProgressBar;
     TextView mIdleText;
     boolean mCanGoBack;
+    AudioManager mAudioManager;
+    AudioManager.OnAudioFocusChangeListener mAudioFocusListener;
 
     @Override
     protected void onCreate(Bundle savedInstanceState) {
@@ -54,6 +57,13 @@ public class MainActivity extends Activity implements Servo.Client {
         mProgressBar = findViewById(R.id.progressbar);
         mIdleText = findViewById(R.id.redrawing);
         mCanGoBack = false;
+        mAudioManager = (AudioManager) getSystemService(Context.AUDIO_SERVICE);
+        mAudioFocusListener = focusChange -> {
+            if (focusChange == AudioManager.AUDIOFOCUS_LOSS ||
+                focusChange == AudioManager.AUDIOFOCUS_LOSS_TRANSIENT) {
+                mServoView.revokeAudioFocus();
+            }
+        };
 
         mBackButton.setEnabled(false);
         mFwdButton.setEnabled(false);
@@ -170,6 +180,17 @@ public class MainActivity extends Activity implements Servo.Client {
         }
     }
 
+    @Override
+    public void onAudioFocusChanged(boolean focused) {
+        if (focused) {
+            mAudioManager.requestAudioFocus(mAudioFocusListener,
+                                            AudioManager.STREAM_MUSIC,
+                                            AudioManager.AUDIOFOCUS_GAIN);
+        } else {
+            mAudioManager.abandonAudioFocus(mAudioFocusListener);
+        }
+    }
+
     @Override
     public void onPause() {
         mServoView.onPause();
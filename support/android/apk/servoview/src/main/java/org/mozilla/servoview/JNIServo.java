@@ -34,6 +34,10 @@ public class JNIServo {
 
     public native void reload();
 
+    public native void setPageFrozen(boolean frozen);
+
+    public native void revokeAudioFocus();
+
     public native void stop();
 
     public native void refresh();
@@ -96,6 +100,8 @@ public class JNIServo {
 
         void onHistoryChanged(boolean canGoBack, boolean canGoForward);
 
+        void onAudioFocusChanged(boolean focused);
+
         void onShutdownComplete();
 
         byte[] readfile(String file);
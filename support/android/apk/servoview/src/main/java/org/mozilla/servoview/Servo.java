@@ -104,6 +104,14 @@ public class Servo {
         mRunCallback.inGLThread(() -> mJNI.reload());
     }
 
+    public void setPageFrozen(boolean frozen) {
+        mRunCallback.inGLThread(() -> mJNI.setPageFrozen(frozen));
+    }
+
+    public void revokeAudioFocus() {
+        mRunCallback.inGLThread(() -> mJNI.revokeAudioFocus());
+    }
+
     public void stop() {
         mRunCallback.inGLThread(() -> mJNI.stop());
     }
@@ -180,6 +188,8 @@ public class Servo {
         void onHistoryChanged(boolean canGoBack, boolean canGoForward);
 
         void onRedrawing(boolean redrawing);
+
+        void onAudioFocusChanged(boolean focused);
     }
 
     public interface RunCallback {
@@ -254,6 +264,10 @@ public class Servo {
             mRunCallback.inUIThread(() -> mClient.onRedrawing(redrawing));
         }
 
+        public void onAudioFocusChanged(boolean focused) {
+            mRunCallback.inUIThread(() -> mClient.onAudioFocusChanged(focused));
+        }
+
         public byte[] readfile(String file) {
             try {
                 InputStream stream = mAssetMgr.open(file);
@@ -97,6 +97,10 @@ public class ServoSurface {
         mServo.stop();
     }
 
+    public void revokeAudioFocus() {
+        mServo.revokeAudioFocus();
+    }
+
     public void loadUri(String uri) {
         if (mServo != null) {
             mServo.loadUri(uri);
@@ -110,6 +110,10 @@ public class ServoView extends GLSurfaceView
         mServo.stop();
     }
 
+    public void revokeAudioFocus() {
+        mServo.revokeAudioFocus();
+    }
+
     public void onSurfaceInvalidated(int width, int height) {
         if (mServo != null) {
             mServo.resize(width, height);
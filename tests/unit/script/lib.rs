@@ -10,6 +10,8 @@ mod htmlareaelement;
 mod htmlimageelement;
 #[cfg(test)]
 mod origin;
+#[cfg(test)]
+mod permissions;
 #[cfg(all(test, target_pointer_width = "64"))]
 mod size_of;
 #[cfg(test)]
@@ -0,0 +1,49 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+
+use script::test::permissions::{embedder_permission_name, stored_permission_state};
+use script::test::permissions::{EmbedderPermissionName, PermissionName, PermissionState};
+
+#[test]
+fn embedder_keeps_media_storage_and_autoplay_decisions() {
+    assert_eq!(
+        embedder_permission_name(&PermissionName::Camera),
+        Some(EmbedderPermissionName::Camera)
+    );
+    assert_eq!(
+        embedder_permission_name(&PermissionName::Microphone),
+        Some(EmbedderPermissionName::Microphone)
+    );
+    assert_eq!(
+        embedder_permission_name(&PermissionName::Persistent_storage),
+        Some(EmbedderPermissionName::PersistentStorage)
+    );
+    assert_eq!(
+        embedder_permission_name(&PermissionName::Autoplay),
+        Some(EmbedderPermissionName::Autoplay)
+    );
+}
+
+#[test]
+fn embedder_does_not_keep_other_decisions() {
+    assert_eq!(embedder_permission_name(&PermissionName::Geolocation), None);
+    assert_eq!(
+        embedder_permission_name(&PermissionName::Notifications),
+        None
+    );
+    assert_eq!(embedder_permission_name(&PermissionName::Bluetooth), None);
+}
+
+#[test]
+fn stored_decision_is_granted_or_denied() {
+    assert_eq!(
+        stored_permission_state(Some(true)),
+        Some(PermissionState::Granted)
+    );
+    assert_eq!(
+        stored_permission_state(Some(false)),
+        Some(PermissionState::Denied)
+    );
+    assert_eq!(stored_permission_state(None), None);
+}
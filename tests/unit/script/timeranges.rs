@@ -85,3 +85,68 @@ fn add_overlapping() {
     time_ranges.add(6., 9.).unwrap();
     check(&time_ranges, "[-1,11)");
 }
+
+#[test]
+fn contains() {
+    let mut time_ranges = TimeRangesContainer::new();
+    time_ranges.add(1., 2.).unwrap();
+    time_ranges.add(4., 6.).unwrap();
+    assert!(!time_ranges.contains(0.5));
+    assert!(time_ranges.contains(1.));
+    assert!(!time_ranges.contains(2.));
+    assert!(time_ranges.contains(5.));
+    assert!(!time_ranges.contains(6.));
+}
+
+#[test]
+fn union() {
+    let mut time_ranges_a = TimeRangesContainer::new();
+    time_ranges_a.add(0., 2.).unwrap();
+    time_ranges_a.add(5., 6.).unwrap();
+
+    let mut time_ranges_b = TimeRangesContainer::new();
+    time_ranges_b.add(1., 3.).unwrap();
+    time_ranges_b.add(8., 9.).unwrap();
+
+    check(&time_ranges_a.union(&time_ranges_b), "[0,3), [5,6), [8,9)");
+    check(&time_ranges_b.union(&time_ranges_a), "[0,3), [5,6), [8,9)");
+    check(
+        &time_ranges_a.union(&TimeRangesContainer::new()),
+        "[0,2), [5,6)",
+    );
+}
+
+#[test]
+fn intersection() {
+    let mut time_ranges_a = TimeRangesContainer::new();
+    time_ranges_a.add(0., 4.).unwrap();
+    time_ranges_a.add(6., 10.).unwrap();
+
+    let mut time_ranges_b = TimeRangesContainer::new();
+    time_ranges_b.add(1., 2.).unwrap();
+    time_ranges_b.add(3., 7.).unwrap();
+    time_ranges_b.add(10., 11.).unwrap();
+
+    let expected = "[1,2), [3,4), [6,7)";
+    check(&time_ranges_a.intersection(&time_ranges_b), expected);
+    check(&time_ranges_b.intersection(&time_ranges_a), expected);
+    check(&time_ranges_a.intersection(&TimeRangesContainer::new()), "");
+}
+
+#[test]
+fn normalize() {
+    let mut time_ranges = TimeRangesContainer::new();
+    time_ranges.add(0., 1.).unwrap();
+    time_ranges.add(1.01, 2.).unwrap();
+    time_ranges.add(2.5, 3.).unwrap();
+    time_ranges.add(3.05, 4.).unwrap();
+
+    time_ranges.normalize(0.);
+    check(&time_ranges, "[0,1), [1.01,2), [2.5,3), [3.05,4)");
+
+    time_ranges.normalize(0.1);
+    check(&time_ranges, "[0,2), [2.5,4)");
+
+    time_ranges.normalize(0.5);
+    check(&time_ranges, "[0,4)");
+}